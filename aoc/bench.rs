@@ -0,0 +1,216 @@
+use crate::solvers::{input_path, run_solver_timed, solvers};
+use aoc::answers::{day_name, part_name};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+pub struct Options {
+    day: Option<String>,
+    part: Option<String>,
+    iterations: usize,
+    warmup: usize,
+    output: Option<PathBuf>,
+    compare: Option<PathBuf>,
+    threshold: f64,
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Stats {
+    pub median: u128,
+    pub p95: u128,
+}
+
+pub fn parse_options(args: &[String]) -> Option<Options> {
+    let mut options = Options {
+        day: None,
+        part: None,
+        iterations: 10,
+        warmup: 2,
+        output: None,
+        compare: None,
+        threshold: 10.0,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" => options.iterations = args.next()?.parse().ok()?,
+            "--warmup" => options.warmup = args.next()?.parse().ok()?,
+            "--output" => options.output = Some(PathBuf::from(args.next()?)),
+            "--compare" => options.compare = Some(PathBuf::from(args.next()?)),
+            "--threshold" => options.threshold = args.next()?.parse().ok()?,
+            _ if options.day.is_none() => options.day = Some(day_name(arg)?),
+            _ if options.part.is_none() => options.part = Some(part_name(arg)?),
+            _ => return None,
+        }
+    }
+    if options.iterations == 0 {
+        return None;
+    }
+    return Some(options);
+}
+
+pub fn stats(samples: &[u128]) -> Stats {
+    let mut sorted = samples.to_vec();
+    sorted.sort();
+    let rank = |p: f64| ((p * sorted.len() as f64).ceil() as usize).clamp(1, sorted.len()) - 1;
+    return Stats {
+        median: sorted[rank(0.5)],
+        p95: sorted[rank(0.95)],
+    };
+}
+
+pub fn run(options: &Options) -> io::Result<bool> {
+    let mut results = vec![];
+    let mut missing_inputs = BTreeSet::new();
+    let selected: Vec<(String, String)> = solvers()
+        .into_iter()
+        .filter(|(day, part)| {
+            options.day.as_ref().is_none_or(|d| d == day)
+                && options.part.as_ref().is_none_or(|p| p == part)
+        })
+        .collect();
+    if selected.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "no solver matches {} {}",
+                options.day.as_deref().unwrap_or("any day"),
+                options.part.as_deref().unwrap_or("any part")
+            ),
+        ));
+    }
+    for (day, part) in selected {
+        let Ok(input) = fs::read_to_string(input_path(&day)) else {
+            if missing_inputs.insert(day.clone()) {
+                eprintln!("{}: no input.txt, skipping", day);
+            }
+            continue;
+        };
+
+        for _ in 0..options.warmup {
+            run_solver_timed(&day, &part, &input)?;
+        }
+        let mut samples: BTreeMap<String, Vec<u128>> = BTreeMap::new();
+        for _ in 0..options.iterations {
+            for (phase, nanos) in run_solver_timed(&day, &part, &input)? {
+                samples.entry(phase).or_default().push(nanos);
+            }
+        }
+
+        let mut phases = serde_json::Map::new();
+        let mut line = format!("{} {}", day, part);
+        for (phase, samples) in &samples {
+            let stats = stats(samples);
+            line.push_str(&format!(
+                "  {} median {} p95 {}",
+                phase,
+                format_nanos(stats.median),
+                format_nanos(stats.p95)
+            ));
+            phases.insert(
+                phase.to_string(),
+                json!({ "median_ns": stats.median, "p95_ns": stats.p95 }),
+            );
+        }
+        println!("{}", line);
+        results.push(json!({
+            "day": day,
+            "part": part,
+            "iterations": options.iterations,
+            "phases": phases,
+        }));
+    }
+
+    let report = json!({ "results": results });
+    if let Some(path) = &options.output {
+        fs::write(path, serde_json::to_string_pretty(&report).unwrap())?;
+    }
+
+    let Some(path) = &options.compare else {
+        return Ok(true);
+    };
+    let previous: Value = serde_json::from_str(&fs::read_to_string(path)?)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+    let regressions = regressions(&previous, &report, options.threshold);
+    for regression in &regressions {
+        println!("regression: {}", regression);
+    }
+    return Ok(regressions.is_empty());
+}
+
+pub fn regressions(previous: &Value, current: &Value, threshold: f64) -> Vec<String> {
+    let mut regressions = vec![];
+    let empty = vec![];
+    let previous_results = previous["results"].as_array().unwrap_or(&empty);
+    for result in current["results"].as_array().unwrap_or(&empty) {
+        let Some(before) = previous_results
+            .iter()
+            .find(|r| r["day"] == result["day"] && r["part"] == result["part"])
+        else {
+            continue;
+        };
+        let Some(phases) = result["phases"].as_object() else {
+            continue;
+        };
+        for (phase, stats) in phases {
+            let (Some(now), Some(then)) = (
+                stats["median_ns"].as_u64(),
+                before["phases"][phase]["median_ns"].as_u64(),
+            ) else {
+                continue;
+            };
+            if now as f64 > then as f64 * (1.0 + threshold / 100.0) {
+                regressions.push(format!(
+                    "{} {} {} median {} -> {}",
+                    result["day"].as_str().unwrap_or_default(),
+                    result["part"].as_str().unwrap_or_default(),
+                    phase,
+                    format_nanos(then as u128),
+                    format_nanos(now as u128)
+                ));
+            }
+        }
+    }
+    return regressions;
+}
+
+fn format_nanos(nanos: u128) -> String {
+    return match nanos {
+        n if n >= 1_000_000_000 => format!("{:.2}s", n as f64 / 1e9),
+        n if n >= 1_000_000 => format!("{:.2}ms", n as f64 / 1e6),
+        n if n >= 1_000 => format!("{:.2}µs", n as f64 / 1e3),
+        n => format!("{}ns", n),
+    };
+}
+
+#[test]
+fn test_stats() -> () {
+    assert_eq!(Stats { median: 3, p95: 5 }, stats(&[5, 1, 4, 2, 3]));
+    assert_eq!(Stats { median: 7, p95: 7 }, stats(&[7]));
+    let samples: Vec<u128> = (1..=100).collect();
+    assert_eq!(
+        Stats {
+            median: 50,
+            p95: 95
+        },
+        stats(&samples)
+    );
+}
+
+#[test]
+fn test_regressions() -> () {
+    let previous = json!({ "results": [
+        { "day": "day-05", "part": "part-02", "phases": { "solve": { "median_ns": 1000, "p95_ns": 1200 } } }
+    ] });
+    let current = json!({ "results": [
+        { "day": "day-05", "part": "part-02", "phases": { "solve": { "median_ns": 1200, "p95_ns": 1300 } } },
+        { "day": "day-06", "part": "part-01", "phases": { "solve": { "median_ns": 9000, "p95_ns": 9000 } } }
+    ] });
+    assert_eq!(
+        vec!["day-05 part-02 solve median 1.00µs -> 1.20µs".to_string()],
+        regressions(&previous, &current, 10.0)
+    );
+    assert!(regressions(&previous, &current, 25.0).is_empty());
+}
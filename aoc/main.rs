@@ -0,0 +1,84 @@
+mod bench;
+mod solvers;
+
+use aoc::answers::{self, day_name, part_name};
+use solvers::{build_solvers, input_path, manifest_dir, run_solver};
+use std::{env, fs, io, process};
+
+fn main() -> io::Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let answers_path = manifest_dir().join("answers.toml");
+
+    match args.first().map(|arg| arg.as_str()) {
+        Some("verify") => {
+            build_solvers()?;
+            if !verify(&answers::load(&answers_path)?)? {
+                process::exit(1);
+            }
+        }
+        Some("record") => {
+            let (Some(day), Some(part)) = (
+                args.get(1).and_then(|day| day_name(day)),
+                args.get(2).and_then(|part| part_name(part)),
+            ) else {
+                return usage();
+            };
+            let input = fs::read_to_string(input_path(&day))?;
+            build_solvers()?;
+            let answer = run_solver(&day, &part, &input)?;
+            let mut registry = answers::load(&answers_path)?;
+            registry
+                .entry(day.clone())
+                .or_default()
+                .insert(part.clone(), answer.clone());
+            answers::save(&answers_path, &registry)?;
+            println!("{} {}: recorded {}", day, part, answer);
+        }
+        Some("bench") => {
+            let Some(options) = bench::parse_options(&args[1..]) else {
+                return usage();
+            };
+            build_solvers()?;
+            if !bench::run(&options)? {
+                process::exit(1);
+            }
+        }
+        _ => return usage(),
+    }
+
+    return Ok(());
+}
+
+fn usage() -> io::Result<()> {
+    eprintln!("usage: aoc verify");
+    eprintln!("       aoc record <day> <part>");
+    eprintln!(
+        "       aoc bench [day] [part] [--iterations N] [--warmup N] [--output FILE] [--compare FILE] [--threshold PERCENT]"
+    );
+    process::exit(2);
+}
+
+fn verify(registry: &answers::Answers) -> io::Result<bool> {
+    let mut ok = true;
+    for (day, parts) in registry {
+        let input = match fs::read_to_string(input_path(day)) {
+            Ok(input) => input,
+            Err(_) => {
+                eprintln!("{}: no input.txt, skipping", day);
+                continue;
+            }
+        };
+        for (part, expected) in parts {
+            let actual = run_solver(day, part, &input)?;
+            if actual == *expected {
+                println!("{} {}: ok", day, part);
+            } else {
+                ok = false;
+                println!("{} {}: answer changed", day, part);
+                println!("- {}", expected);
+                println!("+ {}", actual);
+            }
+        }
+    }
+    return Ok(ok);
+}
@@ -0,0 +1,112 @@
+use aoc::timing::{parse_timing, TIMINGS_VAR};
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+pub fn manifest_dir() -> &'static Path {
+    return Path::new(env!("CARGO_MANIFEST_DIR"));
+}
+
+pub fn input_path(day: &str) -> PathBuf {
+    return manifest_dir().join(day).join("input.txt");
+}
+
+pub fn solvers() -> Vec<(String, String)> {
+    let mut solvers = vec![];
+    for entry in fs::read_dir(manifest_dir()).unwrap() {
+        let day_dir = entry.unwrap().path();
+        let day = day_dir.file_name().unwrap().to_string_lossy().to_string();
+        if !day.starts_with("day-") || !day_dir.is_dir() {
+            continue;
+        }
+        for file in fs::read_dir(&day_dir).unwrap() {
+            let path = file.unwrap().path();
+            let part = path.file_stem().unwrap().to_string_lossy().to_string();
+            if part.starts_with("part-") && path.extension().is_some_and(|ext| ext == "rs") {
+                solvers.push((day.clone(), part));
+            }
+        }
+    }
+    solvers.sort();
+    return solvers;
+}
+
+pub fn solver_path(day: &str, part: &str) -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    return exe
+        .parent()
+        .unwrap()
+        .join(format!("{}-{}{}", day, part, env::consts::EXE_SUFFIX));
+}
+
+/// Rebuilds every solver binary in the profile this tool was built with, so
+/// answers are never checked against stale binaries.
+pub fn build_solvers() -> io::Result<()> {
+    let exe = env::current_exe()?;
+    let mut command = Command::new(env::var("CARGO").unwrap_or("cargo".to_string()));
+    command
+        .arg("build")
+        .arg("--bins")
+        .arg("--manifest-path")
+        .arg(manifest_dir().join("Cargo.toml"));
+    if exe
+        .parent()
+        .and_then(|dir| dir.file_name())
+        .is_some_and(|profile| profile == "release")
+    {
+        command.arg("--release");
+    }
+    let status = command.status()?;
+    if !status.success() {
+        return Err(io::Error::other(format!(
+            "building solvers failed with {}",
+            status
+        )));
+    }
+    return Ok(());
+}
+
+pub fn run_solver(day: &str, part: &str, input: &str) -> io::Result<String> {
+    let output = spawn_solver(day, part, input, Command::new(solver_path(day, part)))?;
+    return Ok(String::from_utf8_lossy(&output.stdout).trim().to_string());
+}
+
+pub fn run_solver_timed(day: &str, part: &str, input: &str) -> io::Result<Vec<(String, u128)>> {
+    let mut command = Command::new(solver_path(day, part));
+    command.env(TIMINGS_VAR, "1");
+    let output = spawn_solver(day, part, input, command)?;
+    return Ok(String::from_utf8_lossy(&output.stderr)
+        .lines()
+        .filter_map(parse_timing)
+        .map(|(phase, nanos)| (phase.to_string(), nanos))
+        .collect());
+}
+
+fn spawn_solver(day: &str, part: &str, input: &str, mut command: Command) -> io::Result<Output> {
+    let path = solver_path(day, part);
+    if !path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} not found, run `cargo build` first", path.display()),
+        ));
+    }
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    child.stdin.take().unwrap().write_all(input.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "{} {} exited with {}: {}",
+            day,
+            part,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    return Ok(output);
+}
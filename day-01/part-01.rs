@@ -1,20 +1,105 @@
-use std::io;
+use aoc::timing::timed;
+use std::env;
+use std::io::{self, BufRead};
+use std::process;
+
+aoc::count_allocations!();
 
 fn main() -> io::Result<()> {
-    let sum = io::stdin().lines().fold(0, |acc, line| {
-        acc + line_value(&line.unwrap_or("".to_string()))
+    let strict = env::args().any(|arg| arg == "--strict");
+    let raw_lines: Vec<Vec<u8>> = io::stdin().lock().split(b'\n').collect::<io::Result<_>>()?;
+
+    let lines: Vec<Option<String>> = timed("parse", || {
+        raw_lines
+            .into_iter()
+            .map(|bytes| {
+                String::from_utf8(bytes)
+                    .ok()
+                    .map(|line| line.trim_end_matches('\r').to_string())
+            })
+            .collect()
+    });
+
+    let (sum, diagnostics) = timed("solve", || {
+        let mut sum = 0;
+        let mut diagnostics = vec![];
+        for (line_index, line) in lines.iter().enumerate() {
+            let Some(line) = line else {
+                diagnostics.push((line_index + 1, Diagnostic::InvalidUtf8));
+                continue;
+            };
+            if let Some(diagnostic) = diagnose(line) {
+                diagnostics.push((line_index + 1, diagnostic));
+            }
+            sum += line_value(line);
+        }
+        (sum, diagnostics)
     });
 
     print!("{}", sum);
 
+    aoc::alloc::report();
+
+    if strict {
+        report(&diagnostics);
+        let invalid = diagnostics.iter().filter(|(_, d)| d.is_invalid()).count();
+        if invalid > 0 {
+            eprintln!("error: {} calibration lines have no usable value", invalid);
+            process::exit(1);
+        }
+    }
+
     return Ok(());
 }
 
+#[derive(Eq, PartialEq, Hash, Debug)]
+enum Diagnostic {
+    NoDigits,
+    SingleDigit,
+    InvalidUtf8,
+}
+
+impl Diagnostic {
+    fn is_invalid(&self) -> bool {
+        return *self != Diagnostic::SingleDigit;
+    }
+}
+
 fn line_value(line: &str) -> u32 {
     let digits: Vec<u32> = line.chars().filter_map(|c| c.to_digit(10)).collect();
     return digits.first().unwrap_or(&0) * 10 + digits.last().unwrap_or(&0);
 }
 
+fn diagnose(line: &str) -> Option<Diagnostic> {
+    return match line.chars().filter(|c| c.is_ascii_digit()).count() {
+        0 => Some(Diagnostic::NoDigits),
+        1 => Some(Diagnostic::SingleDigit),
+        _ => None,
+    };
+}
+
+fn report(diagnostics: &[(usize, Diagnostic)]) {
+    for (line_number, diagnostic) in diagnostics {
+        eprintln!("line {}: {}", line_number, describe(diagnostic));
+    }
+    for diagnostic in [
+        Diagnostic::NoDigits,
+        Diagnostic::SingleDigit,
+        Diagnostic::InvalidUtf8,
+    ] {
+        let count = diagnostics.iter().filter(|(_, d)| *d == diagnostic).count();
+        eprintln!("{}: {}", describe(&diagnostic), count);
+    }
+}
+
+fn describe(diagnostic: &Diagnostic) -> &'static str {
+    return match diagnostic {
+        Diagnostic::NoDigits => "no digits",
+        Diagnostic::SingleDigit => "single digit",
+        Diagnostic::InvalidUtf8 => "invalid UTF-8",
+    };
+}
+
 #[test]
 fn test_line_value() -> () {
     assert_eq!(12, line_value("1abc2"));
@@ -22,3 +107,16 @@ fn test_line_value() -> () {
     assert_eq!(15, line_value("a1b2c3d4e5f"));
     assert_eq!(77, line_value("treb7uchet"));
 }
+
+#[test]
+fn test_diagnose() -> () {
+    assert_eq!(None, diagnose("1abc2"));
+    assert_eq!(None, diagnose("a1b2c3d4e5f"));
+    assert_eq!(Some(Diagnostic::SingleDigit), diagnose("treb7uchet"));
+    assert_eq!(Some(Diagnostic::NoDigits), diagnose("trebuchet"));
+    assert_eq!(Some(Diagnostic::NoDigits), diagnose(""));
+    assert!(!Diagnostic::SingleDigit.is_invalid());
+    assert!(Diagnostic::NoDigits.is_invalid());
+}
+
+aoc::example_tests!(|input: &str| input.lines().map(line_value).sum::<u32>());
@@ -1,42 +1,98 @@
-use std::io;
+use aoc::timing::timed;
+use std::env;
+use std::io::{self, BufRead};
+use std::process;
+
+aoc::count_allocations!();
 
 fn main() -> io::Result<()> {
-    let sum = io::stdin().lines().fold(0, |acc, line| {
-        acc + line_value(&line.unwrap_or("".to_string()))
+    let strict = env::args().any(|arg| arg == "--strict");
+    let raw_lines: Vec<Vec<u8>> = io::stdin().lock().split(b'\n').collect::<io::Result<_>>()?;
+
+    let lines: Vec<Option<String>> = timed("parse", || {
+        raw_lines
+            .into_iter()
+            .map(|bytes| {
+                String::from_utf8(bytes)
+                    .ok()
+                    .map(|line| line.trim_end_matches('\r').to_string())
+            })
+            .collect()
+    });
+
+    let (sum, diagnostics) = timed("solve", || {
+        let mut sum = 0;
+        let mut diagnostics = vec![];
+        for (line_index, line) in lines.iter().enumerate() {
+            let Some(line) = line else {
+                diagnostics.push((line_index + 1, Diagnostic::InvalidUtf8));
+                continue;
+            };
+            if let Some(diagnostic) = diagnose(line) {
+                diagnostics.push((line_index + 1, diagnostic));
+            }
+            sum += line_value(line);
+        }
+        (sum, diagnostics)
     });
 
     print!("{}", sum);
 
+    aoc::alloc::report();
+
+    if strict {
+        report(&diagnostics);
+        let invalid = diagnostics.iter().filter(|(_, d)| d.is_invalid()).count();
+        if invalid > 0 {
+            eprintln!("error: {} calibration lines have no usable value", invalid);
+            process::exit(1);
+        }
+    }
+
     Ok(())
 }
 
+#[derive(Eq, PartialEq, Hash, Debug)]
+enum Diagnostic {
+    NoDigits,
+    SingleDigit,
+    InvalidUtf8,
+}
+
+impl Diagnostic {
+    fn is_invalid(&self) -> bool {
+        return *self != Diagnostic::SingleDigit;
+    }
+}
+
+const LOOKUP: [(&str, u32); 18] = [
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
 fn line_value(line: &str) -> u32 {
-    let lookup = [
-        ("1", 1),
-        ("2", 2),
-        ("3", 3),
-        ("4", 4),
-        ("5", 5),
-        ("6", 6),
-        ("7", 7),
-        ("8", 8),
-        ("9", 9),
-        ("one", 1),
-        ("two", 2),
-        ("three", 3),
-        ("four", 4),
-        ("five", 5),
-        ("six", 6),
-        ("seven", 7),
-        ("eight", 8),
-        ("nine", 9),
-    ];
-    let first = lookup
+    let first = LOOKUP
         .iter()
         .filter_map(|(key, value)| line.find(key).map(|idx| (idx, value)))
         .min_by_key(|(idx, _)| *idx)
         .map_or(0, |(_, value)| *value);
-    let last = lookup
+    let last = LOOKUP
         .iter()
         .filter_map(|(key, value)| line.rfind(key).map(|idx| (idx, value)))
         .max_by_key(|(idx, _)| *idx)
@@ -45,6 +101,38 @@ fn line_value(line: &str) -> u32 {
     return first * 10 + last;
 }
 
+fn diagnose(line: &str) -> Option<Diagnostic> {
+    let first = LOOKUP.iter().filter_map(|(key, _)| line.find(key)).min();
+    let last = LOOKUP.iter().filter_map(|(key, _)| line.rfind(key)).max();
+    return match (first, last) {
+        (Some(first), Some(last)) if first == last => Some(Diagnostic::SingleDigit),
+        (Some(_), Some(_)) => None,
+        _ => Some(Diagnostic::NoDigits),
+    };
+}
+
+fn report(diagnostics: &[(usize, Diagnostic)]) {
+    for (line_number, diagnostic) in diagnostics {
+        eprintln!("line {}: {}", line_number, describe(diagnostic));
+    }
+    for diagnostic in [
+        Diagnostic::NoDigits,
+        Diagnostic::SingleDigit,
+        Diagnostic::InvalidUtf8,
+    ] {
+        let count = diagnostics.iter().filter(|(_, d)| *d == diagnostic).count();
+        eprintln!("{}: {}", describe(&diagnostic), count);
+    }
+}
+
+fn describe(diagnostic: &Diagnostic) -> &'static str {
+    return match diagnostic {
+        Diagnostic::NoDigits => "no digits",
+        Diagnostic::SingleDigit => "single digit",
+        Diagnostic::InvalidUtf8 => "invalid UTF-8",
+    };
+}
+
 #[test]
 fn test_line_value() -> () {
     assert_eq!(29, line_value("two1nine"));
@@ -55,3 +143,14 @@ fn test_line_value() -> () {
     assert_eq!(14, line_value("zoneight234"));
     assert_eq!(76, line_value("7pqrstsixteen"));
 }
+
+#[test]
+fn test_diagnose() -> () {
+    assert_eq!(None, diagnose("two1nine"));
+    assert_eq!(None, diagnose("eightwo"));
+    assert_eq!(Some(Diagnostic::SingleDigit), diagnose("treb7uchet"));
+    assert_eq!(Some(Diagnostic::SingleDigit), diagnose("xxsevenxx"));
+    assert_eq!(Some(Diagnostic::NoDigits), diagnose("trebuchet"));
+}
+
+aoc::example_tests!(|input: &str| input.lines().map(line_value).sum::<u32>());
@@ -0,0 +1,316 @@
+use crate::game::{Bag, Game};
+use itertools::Itertools;
+use std::collections::BTreeMap;
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Admission {
+    pub bag: Bag,
+    pub game_ids: Vec<u32>,
+}
+
+pub fn minimal_bag(game: &Game) -> Bag {
+    let mut bag = Bag::new();
+    for draw in &game.rounds {
+        for (color, count) in &draw.cubes {
+            let max = bag.entry(color.to_string()).or_insert(0);
+            *max = (*max).max(*count);
+        }
+    }
+    return bag;
+}
+
+pub fn format_bag(bag: &Bag) -> String {
+    return bag
+        .iter()
+        .sorted()
+        .map(|(color, count)| format!("{} {}", count, color))
+        .join(", ");
+}
+
+/// The colours whose counts part 2 multiplies.
+pub const POWER_COLORS: [&str; 3] = ["red", "green", "blue"];
+
+/// The product of the red, green and blue counts, where a missing colour
+/// counts as zero.
+pub fn power(bag: &Bag) -> u64 {
+    return POWER_COLORS
+        .iter()
+        .map(|color| bag.get(*color).copied().unwrap_or(0) as u64)
+        .product();
+}
+
+pub fn global_minimal_bag(games: &[Game]) -> Bag {
+    let mut bag = Bag::new();
+    for game in games {
+        for (color, count) in minimal_bag(game) {
+            let max = bag.entry(color).or_insert(0);
+            *max = (*max).max(count);
+        }
+    }
+    return bag;
+}
+
+pub fn power_distribution(games: &[Game]) -> BTreeMap<u64, usize> {
+    let mut distribution = BTreeMap::new();
+    for game in games {
+        *distribution.entry(power(&minimal_bag(game))).or_insert(0) += 1;
+    }
+    return distribution;
+}
+
+/// Finds the bag with the fewest cubes in total under which at least `k` games
+/// are possible. Ties are broken by the lower product of all counts, then by the first bag found
+/// when enumerating candidate counts in ascending colour order.
+///
+/// Colours are searched one at a time over their sorted candidate counts,
+/// keeping only the games still admitted. A branch is abandoned once its
+/// running total plus the `k`-th smallest requirement of every remaining
+/// colour exceeds the best total known, which starts from the bag covering
+/// the `k` games with the smallest minimal bags. The worst case is still
+/// exponential in the number of colours.
+pub fn smallest_bag_admitting(games: &[Game], k: usize) -> Option<Admission> {
+    if k > games.len() {
+        return None;
+    }
+
+    let minimal_bags: Vec<Bag> = games.iter().map(minimal_bag).collect();
+    let colors: Vec<&String> = minimal_bags
+        .iter()
+        .flat_map(|bag| bag.keys())
+        .unique()
+        .sorted()
+        .collect();
+    let needed: Vec<Vec<u32>> = colors
+        .iter()
+        .map(|color| {
+            minimal_bags
+                .iter()
+                .map(|bag| bag.get(*color).copied().unwrap_or(0))
+                .collect()
+        })
+        .collect();
+    let candidates: Vec<Vec<u32>> = needed
+        .iter()
+        .map(|counts| {
+            counts
+                .iter()
+                .copied()
+                .chain([0])
+                .unique()
+                .sorted()
+                .collect()
+        })
+        .collect();
+
+    let greedy_total: u32 = {
+        let chosen: Vec<usize> = (0..games.len())
+            .sorted_by_key(|game| needed.iter().map(|counts| counts[*game]).sum::<u32>())
+            .take(k)
+            .collect();
+        needed
+            .iter()
+            .map(|counts| chosen.iter().map(|game| counts[*game]).max().unwrap_or(0))
+            .sum()
+    };
+
+    let mut search = Search {
+        needed: &needed,
+        candidates: &candidates,
+        k,
+        limit: greedy_total,
+        counts: vec![],
+        best: None,
+    };
+    search.run(0, (0..games.len()).collect(), 0);
+
+    return search.best.map(|(_, _, counts)| {
+        let bag: Bag = colors.iter().map(|c| c.to_string()).zip(counts).collect();
+        Admission {
+            game_ids: games
+                .iter()
+                .filter(|game| game.is_possible(&bag))
+                .map(|game| game.id)
+                .collect(),
+            bag,
+        }
+    });
+}
+
+struct Search<'a> {
+    needed: &'a [Vec<u32>],
+    candidates: &'a [Vec<u32>],
+    k: usize,
+    limit: u32,
+    counts: Vec<u32>,
+    best: Option<(u32, u64, Vec<u32>)>,
+}
+
+impl Search<'_> {
+    fn run(&mut self, color: usize, admitted: Vec<usize>, total: u32) {
+        if color == self.candidates.len() {
+            let key = (total, self.counts.iter().map(|c| *c as u64).product());
+            if self.best.as_ref().is_none_or(|(t, p, _)| key < (*t, *p)) {
+                self.best = Some((key.0, key.1, self.counts.clone()));
+                self.limit = self.limit.min(total);
+            }
+            return;
+        }
+        let remaining = self.lower_bound(color + 1, &admitted);
+        for count in &self.candidates[color] {
+            if total + count + remaining > self.limit {
+                break;
+            }
+            let still_admitted: Vec<usize> = admitted
+                .iter()
+                .copied()
+                .filter(|game| self.needed[color][*game] <= *count)
+                .collect();
+            if still_admitted.len() < self.k {
+                continue;
+            }
+            self.counts.push(*count);
+            self.run(color + 1, still_admitted, total + count);
+            self.counts.pop();
+        }
+    }
+
+    /// Any bag admitting `k` of `admitted` needs at least the `k`-th smallest
+    /// requirement of each colour.
+    fn lower_bound(&self, from_color: usize, admitted: &[usize]) -> u32 {
+        if self.k == 0 {
+            return 0;
+        }
+        return self.needed[from_color..]
+            .iter()
+            .map(|counts| {
+                let mut required: Vec<u32> = admitted.iter().map(|game| counts[*game]).collect();
+                *required.select_nth_unstable(self.k - 1).1
+            })
+            .sum();
+    }
+}
+
+#[cfg(test)]
+fn bag(counts: &[(&str, u32)]) -> Bag {
+    return counts
+        .iter()
+        .map(|(color, count)| (color.to_string(), *count))
+        .collect();
+}
+
+#[cfg(test)]
+fn example_games() -> Vec<Game> {
+    return [
+        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+        "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
+        "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+        "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
+        "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
+    ]
+    .iter()
+    .filter_map(|line| crate::game::parse_game(line))
+    .collect();
+}
+
+#[test]
+fn test_global_minimal_bag() -> () {
+    assert_eq!(
+        bag(&[("red", 20), ("green", 13), ("blue", 15)]),
+        global_minimal_bag(&example_games())
+    );
+}
+
+#[test]
+fn test_minimal_bag() -> () {
+    let game = crate::game::parse_game(
+        "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+    )
+    .unwrap();
+    assert_eq!(
+        bag(&[("red", 20), ("green", 13), ("blue", 6)]),
+        minimal_bag(&game)
+    );
+    assert!(game.is_possible(&minimal_bag(&game)));
+    assert!(!game.is_possible(&bag(&[("red", 19), ("green", 13), ("blue", 6)])));
+}
+
+#[test]
+fn test_power_distribution() -> () {
+    assert_eq!(
+        vec![(12, 1), (36, 1), (48, 1), (630, 1), (1560, 1)],
+        power_distribution(&example_games())
+            .into_iter()
+            .collect::<Vec<(u64, usize)>>()
+    );
+}
+
+#[test]
+fn test_power_counts_missing_colors_as_zero() -> () {
+    let games: Vec<Game> = ["Game 1: 3 blue, 4 red; 1 red, 6 blue", "Game 2:"]
+        .iter()
+        .filter_map(|line| crate::game::parse_game(line))
+        .collect();
+    assert_eq!(0, power(&minimal_bag(&games[0])));
+    assert_eq!(0, power(&minimal_bag(&games[1])));
+    assert_eq!(
+        vec![(0, 2)],
+        power_distribution(&games)
+            .into_iter()
+            .collect::<Vec<(u64, usize)>>()
+    );
+}
+
+#[test]
+fn test_smallest_bag_admitting() -> () {
+    let games = example_games();
+    assert_eq!(
+        Some(Admission {
+            bag: bag(&[("red", 6), ("green", 3), ("blue", 4)]),
+            game_ids: vec![2, 5],
+        }),
+        smallest_bag_admitting(&games, 2)
+    );
+    assert_eq!(
+        Some(vec![1, 2, 3, 4, 5]),
+        smallest_bag_admitting(&games, 5).map(|admission| admission.game_ids)
+    );
+    assert_eq!(None, smallest_bag_admitting(&games, 6));
+}
+
+#[test]
+fn test_smallest_bag_admitting_many_colors() -> () {
+    let colors = [
+        "red", "green", "blue", "cyan", "teal", "pink", "gold", "grey",
+    ];
+    let games: Vec<Game> = (1..=30)
+        .map(|id| {
+            let draws: Vec<String> = colors
+                .iter()
+                .enumerate()
+                .map(|(index, color)| format!("{} {}", (id * 7 + index * 13) % 19 + 1, color))
+                .collect();
+            crate::game::parse_game(&format!("Game {}: {}", id, draws.join(", "))).unwrap()
+        })
+        .collect();
+    let admission = smallest_bag_admitting(&games, 3).unwrap();
+    assert!(admission.game_ids.len() >= 3);
+
+    let best_total = games
+        .iter()
+        .combinations(3)
+        .map(|chosen| {
+            colors
+                .iter()
+                .map(|color| {
+                    chosen
+                        .iter()
+                        .map(|game| minimal_bag(game)[*color])
+                        .max()
+                        .unwrap()
+                })
+                .sum::<u32>()
+        })
+        .min()
+        .unwrap();
+    assert_eq!(best_total, admission.bag.values().sum::<u32>());
+}
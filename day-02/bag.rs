@@ -0,0 +1,85 @@
+use crate::game::{Bag, Game};
+use itertools::Itertools;
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Violation {
+    pub round: usize,
+    pub color: String,
+    pub count: u32,
+    pub limit: u32,
+}
+
+pub fn violations(game: &Game, bag: &Bag) -> Vec<Violation> {
+    return game
+        .rounds
+        .iter()
+        .enumerate()
+        .flat_map(|(round, draw)| {
+            draw.cubes
+                .iter()
+                .map(move |(color, count)| (round, color, *count))
+        })
+        .filter_map(|(round, color, count)| {
+            let limit = bag.get(color).copied().unwrap_or(0);
+            (count > limit).then(|| Violation {
+                round: round + 1,
+                color: color.to_string(),
+                count,
+                limit,
+            })
+        })
+        .sorted_by(|a, b| (a.round, &a.color).cmp(&(b.round, &b.color)))
+        .collect();
+}
+
+pub fn parse_bag(spec: &str) -> Option<Bag> {
+    return spec
+        .split([',', '\n'])
+        .map(|entry| entry.trim())
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (color, count) = entry.split_once("=")?;
+            Some((color.trim().to_string(), count.trim().parse::<u32>().ok()?))
+        })
+        .collect();
+}
+
+#[test]
+fn test_violations() -> () {
+    assert_eq!(
+        vec![
+            Violation {
+                round: 1,
+                color: "red".to_string(),
+                count: 20,
+                limit: 12,
+            },
+            Violation {
+                round: 3,
+                color: "blue".to_string(),
+                count: 15,
+                limit: 14,
+            },
+        ],
+        violations(
+            &crate::game::parse_game(
+                "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 15 blue, 1 red"
+            )
+            .unwrap(),
+            &parse_bag("red=12,green=13,blue=14").unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_parse_bag() -> () {
+    assert_eq!(
+        Some(
+            vec![("red".to_string(), 12), ("teal".to_string(), 3)]
+                .into_iter()
+                .collect()
+        ),
+        parse_bag("red=12, teal = 3\n")
+    );
+    assert_eq!(None, parse_bag("red:12"));
+}
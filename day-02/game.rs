@@ -0,0 +1,123 @@
+use regex::Regex;
+use std::collections::HashMap;
+use std::io;
+use std::sync::LazyLock;
+
+static GAME_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^Game ([0-9]+):(.*)$").unwrap());
+static CUBES_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^([0-9]+) (\w+)$").unwrap());
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Draw>,
+}
+
+pub type Bag = HashMap<String, u32>;
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Draw {
+    pub cubes: HashMap<String, u32>,
+}
+
+impl Game {
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        return self.rounds.iter().all(|draw| {
+            draw.cubes
+                .iter()
+                .all(|(color, count)| *count <= bag.get(color).copied().unwrap_or(0))
+        });
+    }
+}
+
+pub fn parse_game(line: &str) -> Option<Game> {
+    let (_, [id, rounds]) = GAME_PATTERN.captures(line.trim())?.extract();
+    return Some(Game {
+        id: id.parse::<u32>().ok()?,
+        rounds: rounds
+            .split(";")
+            .map(|round| parse_draw(round))
+            .collect::<Option<Vec<Draw>>>()?,
+    });
+}
+
+pub fn parse_games(input: &str) -> io::Result<Vec<Game>> {
+    return input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(line_index, line)| {
+            parse_game(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: invalid game: {:?}", line_index + 1, line),
+                )
+            })
+        })
+        .collect();
+}
+
+fn parse_draw(round: &str) -> Option<Draw> {
+    let mut cubes = HashMap::new();
+    for cube in round.split(",").map(|c| c.trim()).filter(|c| !c.is_empty()) {
+        let (_, [count, color]) = CUBES_PATTERN.captures(cube)?.extract();
+        *cubes.entry(color.to_string()).or_insert(0) += count.parse::<u32>().ok()?;
+    }
+    return Some(Draw { cubes });
+}
+
+#[cfg(test)]
+fn draw(cubes: &[(&str, u32)]) -> Draw {
+    return Draw {
+        cubes: cubes.iter().map(|(c, n)| (c.to_string(), *n)).collect(),
+    };
+}
+
+#[test]
+fn test_parse_game() -> () {
+    assert_eq!(
+        Some(Game {
+            id: 93,
+            rounds: vec![
+                draw(&[("blue", 3)]),
+                draw(&[("blue", 8)]),
+                draw(&[("blue", 3), ("red", 2)]),
+                draw(&[("red", 2)]),
+            ]
+        }),
+        parse_game("Game 93: 3 blue; 8 blue; 3 blue, 2 red; 2 red")
+    );
+    assert_eq!(
+        Some(Game {
+            id: 7,
+            rounds: vec![draw(&[("magenta", 4), ("teal", 1)])]
+        }),
+        parse_game("Game 7: 4 magenta, 1 teal")
+    );
+    assert_eq!(
+        100,
+        parse_game(
+            "Game 100: 5 red, 9 green, 2 blue; 9 blue, 6 green, 1 red; 8 blue, 7 green, 3 red"
+        )
+        .unwrap()
+        .id
+    );
+    assert_eq!(None, parse_game("Game 7: 4 magenta, teal"));
+    assert_eq!(None, parse_game(""));
+}
+
+#[test]
+fn test_parse_games() -> () {
+    assert_eq!(
+        2,
+        parse_games("Game 1: 1 red\n\nGame 2: 2 blue\n")
+            .unwrap()
+            .len()
+    );
+    let err = parse_games("Game 1: 1 red\nGame 2: two blue").unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidData, err.kind());
+    assert_eq!(
+        "line 2: invalid game: \"Game 2: two blue\"",
+        err.to_string()
+    );
+}
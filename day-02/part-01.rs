@@ -1,93 +1,133 @@
-use regex::Regex;
-use std::io;
+mod bag;
+mod game;
+
+use aoc::args::flag_value;
+use aoc::timing::timed;
+use bag::{parse_bag, violations, Violation};
+use game::{parse_games, Bag, Game};
+use std::{env, fs, io};
+
+const DEFAULT_BAG: &str = "red=12,green=13,blue=14";
+
+aoc::count_allocations!();
 
 fn main() -> io::Result<()> {
-    let sum: u32 = io::stdin()
-        .lines()
-        .map(|line| line_value(&line.unwrap_or_default()))
-        .sum();
+    let args: Vec<String> = env::args().skip(1).collect();
+    let bag = get_bag(&args)?;
+    let explain = args.iter().any(|arg| arg == "--explain");
+
+    let input = io::read_to_string(io::stdin())?;
+    let games = timed("parse", || parse_games(&input))?;
+
+    if explain {
+        for game in &games {
+            for violation in violations(game, &bag) {
+                eprintln!("{}", explain_violation(game, &violation));
+            }
+        }
+    }
+
+    let sum: u32 = timed("solve", || {
+        games.iter().map(|game| line_value(game, &bag)).sum()
+    });
 
     print!("{}", sum);
 
+    aoc::alloc::report();
+
     return Ok(());
 }
 
-fn line_value(line: &str) -> u32 {
-    let max_red = 12;
-    let max_green = 13;
-    let max_blue = 14;
-    let game_id = game_id(line);
-    let cc = cube_count(line);
-    if (cc.0 > max_red) || (cc.1 > max_green) || (cc.2 > max_blue) {
-        return 0;
+fn line_value(game: &Game, bag: &Bag) -> u32 {
+    if game.is_possible(bag) {
+        return game.id;
     } else {
-        return game_id;
+        return 0;
     }
 }
 
-fn game_id(line: &str) -> u32 {
-    return Regex::new(r"^Game ([0-9]+):")
-        .unwrap()
-        .captures(line)
-        .unwrap()
-        .get(1)
-        .map_or(0, |m| m.as_str().parse::<u32>().unwrap_or(0));
+fn explain_violation(game: &Game, violation: &Violation) -> String {
+    return format!(
+        "Game {}: round {} draws {} {}, but the bag holds {}",
+        game.id, violation.round, violation.count, violation.color, violation.limit
+    );
 }
 
-fn cube_count(line: &str) -> (u32, u32, u32) {
-    let red = Regex::new(r" ([0-9]+) red")
-        .unwrap()
-        .captures_iter(line)
-        .map(|c| c.extract())
-        .map(|(_, [c])| c)
-        .filter_map(|m| m.parse::<u32>().ok())
-        .max()
-        .unwrap_or(0);
-    let green = Regex::new(r" ([0-9]+) green")
-        .unwrap()
-        .captures_iter(line)
-        .map(|c| c.extract())
-        .map(|(_, [c])| c)
-        .filter_map(|m| m.parse::<u32>().ok())
-        .max()
-        .unwrap_or(0);
-    let blue = Regex::new(r" ([0-9]+) blue")
-        .unwrap()
-        .captures_iter(line)
-        .map(|c| c.extract())
-        .map(|(_, [c])| c)
-        .filter_map(|m| m.parse::<u32>().ok())
-        .max()
-        .unwrap_or(0);
-    return (red, green, blue);
+fn get_bag<S: AsRef<str>>(args: &[S]) -> io::Result<Bag> {
+    let spec = match (flag_value(args, "--bag")?, flag_value(args, "--bag-file")?) {
+        (Some(spec), _) => spec.to_string(),
+        (None, Some(path)) => fs::read_to_string(path)?,
+        (None, None) => DEFAULT_BAG.to_string(),
+    };
+    return parse_bag(&spec)
+        .filter(|bag| !bag.is_empty())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid bag contents: {:?}", spec.trim()),
+            )
+        });
+}
+
+#[cfg(test)]
+fn default_bag() -> Bag {
+    return parse_bag(DEFAULT_BAG).unwrap();
+}
+
+#[cfg(test)]
+fn parse(line: &str) -> Game {
+    return game::parse_game(line).unwrap();
 }
 
 #[test]
-fn test_game_id() -> () {
-    assert_eq!(
-        1,
-        game_id("Game 1: 13 green, 3 red; 4 red, 9 green, 4 blue; 9 green, 10 red, 2 blue")
-    );
-    assert_eq!(93, game_id("Game 93: 3 blue; 8 blue; 3 blue, 2 red; 2 red"));
-    assert_eq!(
-        100,
-        game_id("Game 100: 5 red, 9 green, 2 blue; 9 blue, 6 green, 1 red; 8 blue, 7 green, 3 red")
-    );
+fn test_get_bag() -> () {
+    assert_eq!(default_bag(), get_bag(&["--explain"]).unwrap());
+    assert_eq!(parse_bag("red=1"), get_bag(&["--bag", "red=1"]).ok());
+    for invalid in [
+        &["--bag"][..],
+        &["--bag", ""],
+        &["--bag", " \n"],
+        &["--bag-file"],
+    ] {
+        assert_eq!(
+            io::ErrorKind::InvalidInput,
+            get_bag(invalid).unwrap_err().kind()
+        );
+    }
 }
 
 #[test]
-fn test_cube_count() -> () {
+fn test_line_value() -> () {
+    let bag = default_bag();
     assert_eq!(
-        (8, 2, 4),
-        cube_count("Game 11: 8 red, 4 blue, 1 green; 3 red; 1 green; 2 green, 3 blue")
+        1,
+        line_value(
+            &parse("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"),
+            &bag
+        )
     );
-    assert_eq!((4, 12, 8), cube_count("Game 70: 1 green, 8 blue, 2 red; 2 red, 10 green, 1 blue; 1 red, 12 green, 6 blue; 9 green, 4 blue, 4 red; 2 red, 6 green; 3 red, 8 green, 6 blue"));
     assert_eq!(
-        (2, 1, 8),
-        cube_count("Game 93: 3 blue; 8 blue; 3 blue, 2 red; 2 red, 1 green")
+        0,
+        line_value(
+            &parse("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"),
+            &bag
+        )
     );
+    assert_eq!(0, line_value(&parse("Game 6: 1 red, 1 purple"), &bag));
     assert_eq!(
-        (2, 0, 8),
-        cube_count("Game 93: 3 blue; 8 blue; 3 blue, 2 red; 2 red")
+        6,
+        line_value(
+            &parse("Game 6: 1 red, 1 purple"),
+            &parse_bag("red=1,purple=1").unwrap()
+        )
     );
 }
+
+aoc::example_tests!(|input: &str| {
+    let bag = default_bag();
+    return parse_games(input)
+        .unwrap()
+        .iter()
+        .map(|game| line_value(game, &bag))
+        .sum::<u32>();
+});
@@ -1,70 +1,104 @@
-use regex::Regex;
-use std::io;
+mod analytics;
+mod game;
+
+use analytics::{
+    format_bag, global_minimal_bag, minimal_bag, power, power_distribution, smallest_bag_admitting,
+};
+use aoc::args::number_flag;
+use aoc::timing::timed;
+use game::{parse_games, Game};
+use std::{env, io};
+
+aoc::count_allocations!();
 
 fn main() -> io::Result<()> {
-    let sum: u32 = io::stdin()
-        .lines()
-        .map(|line| line_value(&line.unwrap_or_default()))
-        .sum();
+    let args: Vec<String> = env::args().skip(1).collect();
+    let admit = number_flag(&args, "--admit", 1)?;
+    let input = io::read_to_string(io::stdin())?;
+    let games = timed("parse", || parse_games(&input))?;
+
+    if args.iter().any(|arg| arg == "--stats") {
+        eprintln!(
+            "global minimal bag: {}",
+            format_bag(&global_minimal_bag(&games))
+        );
+        for (power, count) in power_distribution(&games) {
+            eprintln!("power {}: {} games", power, count);
+        }
+    }
+
+    if let Some(k) = admit {
+        match smallest_bag_admitting(&games, k) {
+            Some(admission) => eprintln!(
+                "smallest bag admitting {} games: {} (games {:?})",
+                k,
+                format_bag(&admission.bag),
+                admission.game_ids
+            ),
+            None => eprintln!("no bag admits {} of {} games", k, games.len()),
+        }
+    }
+
+    let sum: u64 = timed("solve", || games.iter().map(|game| line_value(game)).sum());
 
     print!("{}", sum);
 
+    aoc::alloc::report();
+
     return Ok(());
 }
 
-fn line_value(line: &str) -> u32 {
-    let (red, green, blue) = cube_count(line);
-    return red * green * blue;
+fn line_value(game: &Game) -> u64 {
+    return power(&minimal_bag(game));
 }
 
-fn cube_count(line: &str) -> (u32, u32, u32) {
-    let red = Regex::new(r" ([0-9]+) red")
-        .unwrap()
-        .captures_iter(line)
-        .map(|c| c.extract())
-        .map(|(_, [c])| c)
-        .filter_map(|m| m.parse::<u32>().ok())
-        .max()
-        .unwrap_or(0);
-    let green = Regex::new(r" ([0-9]+) green")
-        .unwrap()
-        .captures_iter(line)
-        .map(|c| c.extract())
-        .map(|(_, [c])| c)
-        .filter_map(|m| m.parse::<u32>().ok())
-        .max()
-        .unwrap_or(0);
-    let blue = Regex::new(r" ([0-9]+) blue")
-        .unwrap()
-        .captures_iter(line)
-        .map(|c| c.extract())
-        .map(|(_, [c])| c)
-        .filter_map(|m| m.parse::<u32>().ok())
-        .max()
-        .unwrap_or(0);
-    return (red, green, blue);
+#[cfg(test)]
+fn parse(line: &str) -> Game {
+    return game::parse_game(line).unwrap();
 }
 
 #[test]
 fn test_line_value() -> () {
     assert_eq!(
         48,
-        line_value("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green")
+        line_value(&parse(
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
+        ))
     );
     assert_eq!(
         12,
-        line_value("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue")
+        line_value(&parse(
+            "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue"
+        ))
     );
     assert_eq!(
         1560,
-        line_value("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red")
+        line_value(&parse(
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"
+        ))
     );
     assert_eq!(
         630,
-        line_value("Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red")
+        line_value(&parse(
+            "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red"
+        ))
     );
     assert_eq!(
         36,
-        line_value("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green")
+        line_value(&parse(
+            "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"
+        ))
+    );
+    assert_eq!(
+        0,
+        line_value(&parse("Game 6: 3 blue, 4 red; 1 red, 6 blue"))
     );
 }
+
+aoc::example_tests!(|input: &str| {
+    return parse_games(input)
+        .unwrap()
+        .iter()
+        .map(line_value)
+        .sum::<u64>();
+});
@@ -1,101 +1,62 @@
-use regex::Regex;
-use std::collections::HashSet;
-use std::io;
+mod render;
+mod report;
+mod schematic;
+
+use aoc::args::{flag_value, number_flag};
+use aoc::timing::timed;
+use render::{render_ansi, render_html};
+use report::{analyse, format_report};
+use schematic::Schematic;
+#[cfg(test)]
 use std::str::Lines;
+use std::{env, fs, io};
+
+aoc::count_allocations!();
 
 fn main() -> io::Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let arity = number_flag(&args, "--arity", 1)?.unwrap_or(2);
+    let html = flag_value(&args, "--html")?;
     let input = io::read_to_string(io::stdin())?;
-    let sum = get_result(input.lines());
-    print!("{}", sum);
-
-    return Ok(());
-}
-
-fn get_result(lines: Lines) -> u32 {
-    let symbols = get_symbols(lines.clone());
-    let numbers = get_numbers(lines);
-    return symbols
-        .iter()
-        .map(|s| get_adjacent_numbers(s, &numbers))
-        .filter(|ns| ns.len() == 2)
-        .map(|ns| ns[0] * ns[1])
-        .sum();
-}
+    let schematic = timed("parse", || Schematic::parse(input.lines()));
 
-#[derive(Eq, PartialEq, Hash, Debug)]
-struct Sym {
-    line_index: usize,
-    index: usize,
-}
-
-#[derive(Eq, PartialEq, Hash, Debug)]
-struct Num {
-    line_index: usize,
-    start_index: usize,
-    end_index: usize,
-    value: u32,
-}
+    let render = args.iter().any(|arg| arg == "--render");
+    let report = args.iter().any(|arg| arg == "--report");
+    if report || render || html.is_some() {
+        let analysis = analyse(&schematic, arity);
+        if report {
+            eprint!("{}", format_report(&schematic, &analysis));
+        }
+        if render {
+            eprint!("{}", render_ansi(&schematic, &analysis));
+        }
+        if let Some(path) = html {
+            fs::write(path, render_html(&schematic, &analysis))?;
+        }
+    }
 
-fn get_numbers(lines: Lines) -> Vec<Num> {
-    return lines
-        .enumerate()
-        .flat_map(|(line_index, line)| get_numbers_in_line(line_index, line))
-        .collect();
-}
+    let sum = timed("solve", || solve(&schematic));
+    print!("{}", sum);
 
-fn get_numbers_in_line(line_index: usize, line: &str) -> Vec<Num> {
-    return Regex::new(r"[0-9]+")
-        .unwrap()
-        .find_iter(line)
-        .map(|m| Num {
-            line_index: line_index,
-            start_index: m.start(),
-            end_index: m.end().checked_sub(1).unwrap_or(m.end()),
-            value: m.as_str().parse::<u32>().unwrap(),
-        })
-        .collect();
-}
+    aoc::alloc::report();
 
-fn get_symbols(lines: Lines) -> HashSet<Sym> {
-    return lines
-        .enumerate()
-        .flat_map(|(line_index, line)| get_symbols_in_line(line_index, line))
-        .collect();
+    return Ok(());
 }
 
-fn get_symbols_in_line(line_index: usize, line: &str) -> HashSet<Sym> {
-    return Regex::new(r"\*")
-        .unwrap()
-        .find_iter(line)
-        .map(|m| Sym {
-            line_index: line_index,
-            index: m.start(),
-        })
-        .collect();
+#[cfg(test)]
+fn get_result(lines: Lines) -> u32 {
+    return solve(&Schematic::parse(lines));
 }
 
-fn get_adjacent_numbers(symbol: &Sym, numbers: &Vec<Num>) -> Vec<u32> {
-    return numbers
+fn solve(schematic: &Schematic) -> u32 {
+    let adjacency = schematic.adjacency();
+    return schematic
+        .symbols
         .iter()
-        .filter(|n| is_adjacent(symbol, n))
-        .map(|n| n.value)
-        .collect();
-}
-
-fn is_adjacent(symbol: &Sym, num: &Num) -> bool {
-    let min_li = symbol
-        .line_index
-        .checked_sub(1)
-        .unwrap_or(symbol.line_index);
-    let max_li = symbol.line_index + 1;
-
-    let min_i = symbol.index.checked_sub(1).unwrap_or(symbol.index);
-    let max_i = symbol.index + 1;
-
-    return num.line_index >= min_li
-        && num.line_index <= max_li
-        && num.start_index <= max_i
-        && num.end_index >= min_i;
+        .zip(adjacency.numbers_by_symbol.iter())
+        .filter(|(s, ns)| s.symbol == '*' && ns.len() == 2)
+        .map(|(_, ns)| schematic.numbers[ns[0]].value * schematic.numbers[ns[1]].value)
+        .sum();
 }
 
 #[test]
@@ -115,20 +76,6 @@ fn test_get_result() -> () {
         755 * 598,
         get_result("......755.\n...$.*....\n.664.598..".lines()),
     );
-    assert_eq!(
-        467835,
-        get_result(
-            "467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598.."
-                .lines()
-        )
-    );
 }
+
+aoc::example_tests!(|input: &str| get_result(input.lines()));
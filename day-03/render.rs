@@ -0,0 +1,157 @@
+use crate::report::{gear_ratio, Report};
+use crate::schematic::Schematic;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+enum Cell {
+    Blank,
+    Symbol,
+    Gear,
+    PartNumber,
+    LoneNumber,
+}
+
+struct Annotated {
+    rows: Vec<Vec<(char, Cell)>>,
+    ratios: Vec<Vec<u64>>,
+}
+
+fn annotate(schematic: &Schematic, report: &Report) -> Annotated {
+    let grid = &schematic.grid;
+    let mut cells = grid.map(|c| if *c == '.' { Cell::Blank } else { Cell::Symbol });
+    for (id, num) in schematic.numbers.iter().enumerate() {
+        let cell = if report.isolated_numbers.contains(&id) {
+            Cell::LoneNumber
+        } else {
+            Cell::PartNumber
+        };
+        for index in num.start_index..num.end_index {
+            cells.set((num.line_index, index), cell);
+        }
+    }
+
+    let mut ratios = vec![vec![]; grid.height()];
+    for (symbol_id, number_ids) in &report.gears {
+        let symbol = &schematic.symbols[*symbol_id];
+        cells.set((symbol.line_index, symbol.index), Cell::Gear);
+        ratios[symbol.line_index].push(gear_ratio(schematic, number_ids));
+    }
+
+    return Annotated {
+        rows: grid
+            .rows()
+            .zip(cells.rows())
+            .map(|(chars, cells)| chars.iter().copied().zip(cells.iter().copied()).collect())
+            .collect(),
+        ratios,
+    };
+}
+
+pub fn render_ansi(schematic: &Schematic, report: &Report) -> String {
+    let annotated = annotate(schematic, report);
+    let mut out = String::new();
+    for (row, ratios) in annotated.rows.iter().zip(&annotated.ratios) {
+        for (c, cell) in row {
+            let style = match cell {
+                Cell::Blank => "2",
+                Cell::Symbol => "36",
+                Cell::Gear => "1;30;43",
+                Cell::PartNumber => "32",
+                Cell::LoneNumber => "31",
+            };
+            out.push_str(&format!("\x1b[{}m{}\x1b[0m", style, c));
+        }
+        for ratio in ratios {
+            out.push_str(&format!("  \x1b[33mgear ratio {}\x1b[0m", ratio));
+        }
+        out.push('\n');
+    }
+    return out;
+}
+
+pub fn render_html(schematic: &Schematic, report: &Report) -> String {
+    let annotated = annotate(schematic, report);
+    let mut out = String::from(
+        "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>Schematic</title>
+<style>
+body { background: #0f0f23; color: #cccccc; }
+.blank { color: #555555; }
+.symbol { color: #00cccc; }
+.gear { background: #ffff66; color: #0f0f23; font-weight: bold; }
+.part { color: #00cc00; }
+.lone { color: #ff4444; }
+.ratio { color: #ffff66; }
+</style>
+</head>
+<body>
+<pre>
+",
+    );
+    for (row, ratios) in annotated.rows.iter().zip(&annotated.ratios) {
+        for (c, cell) in row {
+            let class = match cell {
+                Cell::Blank => "blank",
+                Cell::Symbol => "symbol",
+                Cell::Gear => "gear",
+                Cell::PartNumber => "part",
+                Cell::LoneNumber => "lone",
+            };
+            out.push_str(&format!("<span class=\"{}\">{}</span>", class, escape(*c)));
+        }
+        for ratio in ratios {
+            out.push_str(&format!(
+                "  <span class=\"ratio\">gear ratio {}</span>",
+                ratio
+            ));
+        }
+        out.push('\n');
+    }
+    out.push_str("</pre>\n</body>\n</html>\n");
+    return out;
+}
+
+fn escape(c: char) -> String {
+    return match c {
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '&' => "&amp;".to_string(),
+        '"' => "&quot;".to_string(),
+        _ => c.to_string(),
+    };
+}
+
+#[test]
+fn test_annotate() -> () {
+    let schematic = Schematic::parse("467..114\n...*....\n..35....".lines());
+    let report = crate::report::analyse(&schematic, 2);
+    let annotated = annotate(&schematic, &report);
+    assert_eq!(
+        vec![
+            Cell::PartNumber,
+            Cell::PartNumber,
+            Cell::PartNumber,
+            Cell::Blank,
+            Cell::Blank,
+            Cell::LoneNumber,
+            Cell::LoneNumber,
+            Cell::LoneNumber,
+        ],
+        annotated.rows[0]
+            .iter()
+            .map(|(_, cell)| *cell)
+            .collect::<Vec<Cell>>()
+    );
+    assert_eq!(('*', Cell::Gear), annotated.rows[1][3]);
+    assert_eq!(vec![vec![], vec![467 * 35], vec![]], annotated.ratios);
+}
+
+#[test]
+fn test_render_html() -> () {
+    let schematic = Schematic::parse("1&.\n...".lines());
+    let report = crate::report::analyse(&schematic, 2);
+    let html = render_html(&schematic, &report);
+    assert!(html.contains("<span class=\"part\">1</span><span class=\"symbol\">&amp;</span>"));
+}
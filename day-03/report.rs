@@ -0,0 +1,153 @@
+use crate::schematic::Schematic;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Report {
+    pub numbers_by_symbol: BTreeMap<char, Vec<usize>>,
+    pub gears: Vec<(usize, Vec<usize>)>,
+    pub isolated_numbers: Vec<usize>,
+    pub shared_numbers: Vec<(usize, Vec<usize>)>,
+}
+
+pub fn analyse(schematic: &Schematic, arity: usize) -> Report {
+    let adjacency = schematic.adjacency();
+
+    let mut numbers_by_symbol: BTreeMap<char, Vec<usize>> = BTreeMap::new();
+    for (symbol, number_ids) in schematic.symbols.iter().zip(&adjacency.numbers_by_symbol) {
+        numbers_by_symbol
+            .entry(symbol.symbol)
+            .or_default()
+            .extend(number_ids);
+    }
+    for number_ids in numbers_by_symbol.values_mut() {
+        number_ids.sort();
+        number_ids.dedup();
+    }
+
+    return Report {
+        numbers_by_symbol,
+        gears: schematic
+            .symbols
+            .iter()
+            .zip(&adjacency.numbers_by_symbol)
+            .enumerate()
+            .filter(|(_, (symbol, number_ids))| symbol.symbol == '*' && number_ids.len() == arity)
+            .map(|(symbol_id, (_, number_ids))| (symbol_id, number_ids.clone()))
+            .collect(),
+        isolated_numbers: (0..schematic.numbers.len())
+            .filter(|id| adjacency.symbols_by_number[*id].is_empty())
+            .collect(),
+        shared_numbers: adjacency
+            .symbols_by_number
+            .iter()
+            .enumerate()
+            .filter(|(_, symbol_ids)| symbol_ids.len() > 1)
+            .map(|(number_id, symbol_ids)| (number_id, symbol_ids.clone()))
+            .collect(),
+    };
+}
+
+pub fn gear_ratio(schematic: &Schematic, number_ids: &[usize]) -> u64 {
+    return number_ids
+        .iter()
+        .map(|id| schematic.numbers[*id].value as u64)
+        .product();
+}
+
+pub fn format_report(schematic: &Schematic, report: &Report) -> String {
+    let value = |id: &usize| schematic.numbers[*id].value.to_string();
+    let position = |id: &usize| {
+        let symbol = &schematic.symbols[*id];
+        format!(
+            "{} at {}:{}",
+            symbol.symbol,
+            symbol.line_index + 1,
+            symbol.index + 1
+        )
+    };
+
+    let mut out = String::new();
+    for (symbol, number_ids) in &report.numbers_by_symbol {
+        let values: Vec<String> = number_ids.iter().map(value).collect();
+        writeln!(
+            out,
+            "part numbers next to {}: {}",
+            symbol,
+            values.join(", ")
+        )
+        .unwrap();
+    }
+    for (symbol_id, number_ids) in &report.gears {
+        let values: Vec<String> = number_ids.iter().map(value).collect();
+        writeln!(
+            out,
+            "gear {}: {} (ratio {})",
+            position(symbol_id),
+            values.join(" * "),
+            gear_ratio(schematic, number_ids)
+        )
+        .unwrap();
+    }
+    let values: Vec<String> = report.isolated_numbers.iter().map(value).collect();
+    writeln!(out, "numbers next to no symbol: {}", values.join(", ")).unwrap();
+    for (number_id, symbol_ids) in &report.shared_numbers {
+        let positions: Vec<String> = symbol_ids.iter().map(position).collect();
+        writeln!(
+            out,
+            "number {} touches {}",
+            value(number_id),
+            positions.join(", ")
+        )
+        .unwrap();
+    }
+    return out;
+}
+
+#[test]
+fn test_analyse() -> () {
+    let schematic = Schematic::parse(
+        "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."
+            .lines(),
+    );
+    let report = analyse(&schematic, 2);
+    let values =
+        |ids: &[usize]| -> Vec<u32> { ids.iter().map(|id| schematic.numbers[*id].value).collect() };
+
+    assert_eq!(
+        vec!['#', '$', '*', '+'],
+        report
+            .numbers_by_symbol
+            .keys()
+            .copied()
+            .collect::<Vec<char>>()
+    );
+    assert_eq!(
+        vec![467, 35, 617, 755, 598],
+        values(&report.numbers_by_symbol[&'*'])
+    );
+    assert_eq!(
+        vec![16345, 451490],
+        report
+            .gears
+            .iter()
+            .map(|(_, ids)| gear_ratio(&schematic, ids))
+            .collect::<Vec<u64>>()
+    );
+    assert_eq!(vec![114, 58], values(&report.isolated_numbers));
+    assert!(report.shared_numbers.is_empty());
+
+    let shared = Schematic::parse("1*.\n.5.\n.#.".lines());
+    let report = analyse(&shared, 1);
+    assert_eq!(vec![(1, vec![0, 1])], report.shared_numbers);
+    assert_eq!(vec![(0, vec![0, 1])], analyse(&shared, 2).gears);
+}
@@ -0,0 +1,160 @@
+use aoc::grid::Grid;
+use std::str::Lines;
+
+#[derive(Eq, PartialEq, Hash, Debug)]
+pub struct Sym {
+    pub line_index: usize,
+    pub index: usize,
+    pub symbol: char,
+}
+
+#[derive(Eq, PartialEq, Hash, Debug)]
+pub struct Num {
+    pub line_index: usize,
+    pub start_index: usize,
+    pub end_index: usize,
+    pub value: u32,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Adjacency {
+    pub numbers_by_symbol: Vec<Vec<usize>>,
+    pub symbols_by_number: Vec<Vec<usize>>,
+}
+
+pub struct Schematic {
+    pub grid: Grid<char>,
+    pub symbols: Vec<Sym>,
+    pub numbers: Vec<Num>,
+    number_ids: Grid<Option<usize>>,
+}
+
+impl Schematic {
+    pub fn parse(lines: Lines) -> Schematic {
+        let grid = Grid::from_lines(lines, |c| c);
+        let symbols = get_symbols(&grid);
+        let numbers = get_numbers(&grid);
+        let mut number_ids = grid.map(|_| None);
+        for (id, num) in numbers.iter().enumerate() {
+            for index in num.start_index..num.end_index {
+                number_ids.set((num.line_index, index), Some(id));
+            }
+        }
+        return Schematic {
+            grid,
+            symbols,
+            numbers,
+            number_ids,
+        };
+    }
+
+    pub fn number_at(&self, line_index: usize, index: usize) -> Option<usize> {
+        return self.number_ids.get((line_index, index)).copied().flatten();
+    }
+
+    pub fn adjacent_numbers(&self, symbol: &Sym) -> Vec<usize> {
+        let mut ids: Vec<usize> = self
+            .grid
+            .neighbours8((symbol.line_index, symbol.index))
+            .filter_map(|(line_index, index)| self.number_at(line_index, index))
+            .collect();
+        ids.sort();
+        ids.dedup();
+        return ids;
+    }
+
+    pub fn adjacency(&self) -> Adjacency {
+        let numbers_by_symbol: Vec<Vec<usize>> = self
+            .symbols
+            .iter()
+            .map(|symbol| self.adjacent_numbers(symbol))
+            .collect();
+        let mut symbols_by_number = vec![vec![]; self.numbers.len()];
+        for (symbol_id, number_ids) in numbers_by_symbol.iter().enumerate() {
+            for number_id in number_ids {
+                symbols_by_number[*number_id].push(symbol_id);
+            }
+        }
+        return Adjacency {
+            numbers_by_symbol,
+            symbols_by_number,
+        };
+    }
+}
+
+fn get_numbers(grid: &Grid<char>) -> Vec<Num> {
+    let mut numbers = vec![];
+    for (line_index, row) in grid.rows().enumerate() {
+        let mut index = 0;
+        while index < row.len() {
+            if !row[index].is_ascii_digit() {
+                index += 1;
+                continue;
+            }
+            let start_index = index;
+            while index < row.len() && row[index].is_ascii_digit() {
+                index += 1;
+            }
+            numbers.push(Num {
+                line_index,
+                start_index,
+                end_index: index,
+                value: row[start_index..index]
+                    .iter()
+                    .collect::<String>()
+                    .parse::<u32>()
+                    .unwrap(),
+            });
+        }
+    }
+    return numbers;
+}
+
+fn get_symbols(grid: &Grid<char>) -> Vec<Sym> {
+    return grid
+        .iter()
+        .filter(|(_, c)| !c.is_ascii_digit() && **c != '.')
+        .map(|((line_index, index), c)| Sym {
+            line_index,
+            index,
+            symbol: *c,
+        })
+        .collect();
+}
+
+#[test]
+fn test_parse() -> () {
+    let schematic = Schematic::parse("467..114..\n...*......\n..35..633.".lines());
+    assert_eq!(
+        vec![Sym {
+            line_index: 1,
+            index: 3,
+            symbol: '*'
+        }],
+        schematic.symbols
+    );
+    assert_eq!(
+        Num {
+            line_index: 0,
+            start_index: 5,
+            end_index: 8,
+            value: 114
+        },
+        schematic.numbers[1]
+    );
+    assert_eq!(Some(0), schematic.number_at(0, 2));
+    assert_eq!(None, schematic.number_at(0, 3));
+    assert_eq!(Some(3), schematic.number_at(2, 8));
+}
+
+#[test]
+fn test_adjacency() -> () {
+    let schematic = Schematic::parse("467..114..\n...*......\n..35..633.".lines());
+    assert_eq!(
+        Adjacency {
+            numbers_by_symbol: vec![vec![0, 2]],
+            symbols_by_number: vec![vec![0], vec![], vec![0], vec![]],
+        },
+        schematic.adjacency()
+    );
+}
@@ -0,0 +1,88 @@
+use aoc::timing::timed;
+use std::{collections::HashSet, io, str::Lines};
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let raw_input = io::read_to_string(io::stdin())?;
+    let input = timed("parse", || parse_input(raw_input.lines()));
+    print!("{}", timed("solve", || get_result(&input)));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+#[derive(Eq, PartialEq, Debug)]
+struct Input {
+    cards: Vec<Card>,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+struct Card {
+    id: u32,
+    winning: Vec<u32>,
+    have: Vec<u32>,
+}
+
+fn get_result(input: &Input) -> u32 {
+    return input
+        .cards
+        .iter()
+        .map(|card| match matches(card) {
+            0 => 0,
+            n => 2u32.pow(n as u32 - 1),
+        })
+        .sum();
+}
+
+fn matches(card: &Card) -> usize {
+    let winning: HashSet<&u32> = card.winning.iter().collect();
+    return card.have.iter().filter(|n| winning.contains(n)).count();
+}
+
+fn parse_input(lines: Lines) -> Input {
+    return Input {
+        cards: lines
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| parse_card(line))
+            .collect(),
+    };
+}
+
+fn parse_card(line: &str) -> Card {
+    let (label, numbers) = line.split_once(":").unwrap();
+    let (winning, have) = numbers.split_once("|").unwrap();
+    return Card {
+        id: label
+            .split_whitespace()
+            .last()
+            .unwrap()
+            .parse::<u32>()
+            .unwrap(),
+        winning: parse_numbers(winning),
+        have: parse_numbers(have),
+    };
+}
+
+fn parse_numbers(numbers: &str) -> Vec<u32> {
+    return numbers
+        .split_whitespace()
+        .map(|n| n.parse::<u32>().unwrap())
+        .collect();
+}
+
+#[test]
+fn test_parse_card() -> () {
+    assert_eq!(
+        Card {
+            id: 3,
+            winning: vec![1, 21, 53, 59, 44],
+            have: vec![69, 82, 63, 72, 16, 21, 14, 1],
+        },
+        parse_card("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1")
+    );
+}
+
+aoc::example_tests!(|input: &str| get_result(&parse_input(input.lines())));
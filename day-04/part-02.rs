@@ -0,0 +1,187 @@
+use aoc::timing::timed;
+use num::BigUint;
+use std::{
+    collections::{HashMap, HashSet},
+    env, io,
+    str::Lines,
+};
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let raw_input = io::read_to_string(io::stdin())?;
+    let input = timed("parse", || parse_input(raw_input.lines()));
+    let cascade = timed("solve", || get_cascade(&input));
+
+    if env::args().any(|arg| arg == "--copies") {
+        for (card, copies) in input.cards.iter().zip(&cascade.copies) {
+            eprintln!("card {}: {} copies", card.id, copies);
+        }
+    }
+    for overflow in &cascade.overflows {
+        eprintln!(
+            "warning: card {} wins copies of {} cards past the end of the table",
+            overflow.card, overflow.missing
+        );
+    }
+
+    print!("{}", get_result(&cascade));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+#[derive(Eq, PartialEq, Debug)]
+struct Input {
+    cards: Vec<Card>,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+struct Card {
+    id: u32,
+    winning: Vec<u32>,
+    have: Vec<u32>,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+struct Cascade {
+    copies: Vec<BigUint>,
+    overflows: Vec<Overflow>,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+struct Overflow {
+    card: u32,
+    missing: usize,
+}
+
+fn get_result(cascade: &Cascade) -> BigUint {
+    return cascade.copies.iter().sum();
+}
+
+fn get_cascade(input: &Input) -> Cascade {
+    let index_by_id: HashMap<u32, usize> = input
+        .cards
+        .iter()
+        .enumerate()
+        .map(|(index, card)| (card.id, index))
+        .collect();
+    let mut copies = vec![BigUint::from(1u32); input.cards.len()];
+    let mut overflows = vec![];
+
+    for (index, card) in input.cards.iter().enumerate() {
+        let won = copies[index].clone();
+        let mut missing = 0;
+        for id in (card.id + 1)..=(card.id + matches(card) as u32) {
+            match index_by_id.get(&id) {
+                Some(next) => copies[*next] += &won,
+                None => missing += 1,
+            }
+        }
+        if missing > 0 {
+            overflows.push(Overflow {
+                card: card.id,
+                missing,
+            });
+        }
+    }
+
+    return Cascade { copies, overflows };
+}
+
+fn matches(card: &Card) -> usize {
+    let winning: HashSet<&u32> = card.winning.iter().collect();
+    return card.have.iter().filter(|n| winning.contains(n)).count();
+}
+
+fn parse_input(lines: Lines) -> Input {
+    return Input {
+        cards: lines
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| parse_card(line))
+            .collect(),
+    };
+}
+
+fn parse_card(line: &str) -> Card {
+    let (label, numbers) = line.split_once(":").unwrap();
+    let (winning, have) = numbers.split_once("|").unwrap();
+    return Card {
+        id: label
+            .split_whitespace()
+            .last()
+            .unwrap()
+            .parse::<u32>()
+            .unwrap(),
+        winning: parse_numbers(winning),
+        have: parse_numbers(have),
+    };
+}
+
+fn parse_numbers(numbers: &str) -> Vec<u32> {
+    return numbers
+        .split_whitespace()
+        .map(|n| n.parse::<u32>().unwrap())
+        .collect();
+}
+
+#[test]
+fn test_parse_card() -> () {
+    assert_eq!(
+        Card {
+            id: 3,
+            winning: vec![1, 21, 53, 59, 44],
+            have: vec![69, 82, 63, 72, 16, 21, 14, 1],
+        },
+        parse_card("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1")
+    );
+}
+
+#[test]
+fn test_get_cascade() -> () {
+    let input = "
+    Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+    Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+    Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+    Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+    Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+    Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+    let cascade = get_cascade(&parse_input(input.lines()));
+    assert_eq!(
+        vec![1u32, 2, 4, 8, 14, 1]
+            .into_iter()
+            .map(BigUint::from)
+            .collect::<Vec<BigUint>>(),
+        cascade.copies
+    );
+    assert!(cascade.overflows.is_empty());
+
+    let cascade = get_cascade(&parse_input("Card 1: 1 2 | 1 2\nCard 2: 3 | 3".lines()));
+    assert_eq!(
+        vec![
+            Overflow {
+                card: 1,
+                missing: 1
+            },
+            Overflow {
+                card: 2,
+                missing: 1
+            }
+        ],
+        cascade.overflows
+    );
+    assert_eq!(BigUint::from(3u32), get_result(&cascade));
+}
+
+#[test]
+fn test_get_cascade_overflowing_u128() -> () {
+    let input: String = (1..=200)
+        .map(|id| format!("Card {}: 1 2 3 4 5 | 1 2 3 4 5\n", id))
+        .collect();
+    let total = get_result(&get_cascade(&parse_input(input.lines())));
+    assert!(total > BigUint::from(u128::MAX));
+}
+
+aoc::example_tests!(|input: &str| get_result(&get_cascade(&parse_input(input.lines()))));
@@ -1,11 +1,16 @@
-use std::{collections::HashMap, io, str::Lines, vec};
+use aoc::timing::timed;
+use std::{io, str::Lines, vec};
+
+aoc::count_allocations!();
 
 fn main() -> io::Result<()> {
     let input = io::read_to_string(io::stdin())?;
-    let almanac = get_almanac(input.lines());
-    let lowest_location = get_lowest_location(almanac);
+    let almanac = timed("parse", || get_almanac(input.lines()));
+    let lowest_location = timed("solve", || get_lowest_location(almanac));
     print!("{}", lowest_location);
 
+    aoc::alloc::report();
+
     return Ok(());
 }
 
@@ -65,8 +70,7 @@ fn apply_mapping(value: u64, map: &CategoryMap) -> u64 {
 
 fn get_almanac(lines: Lines) -> Almanac {
     let mut seeds = vec![];
-    let mut key: Option<&str> = None;
-    let mut tmp_map: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut tmp_map: Vec<(&str, Vec<&str>)> = vec![];
 
     for line in lines {
         if line.trim().starts_with("seeds: ") {
@@ -78,14 +82,11 @@ fn get_almanac(lines: Lines) -> Almanac {
             continue;
         }
         if line.trim().ends_with(" map:") {
-            key = Some(line.trim());
+            tmp_map.push((line.trim(), vec![]));
             continue;
         }
-        if !line.trim().is_empty() && key.is_some() {
-            tmp_map
-                .entry(key.unwrap())
-                .or_insert(vec![])
-                .push(line.trim());
+        if let Some((_, entries)) = tmp_map.last_mut().filter(|_| !line.trim().is_empty()) {
+            entries.push(line.trim());
         }
     }
 
@@ -187,42 +188,4 @@ fn test_get_almanac() -> () {
     assert_eq!(expected, get_almanac(test_input.lines()));
 }
 
-#[test]
-fn test_get_lowest_location() -> () {
-    let test_input = "
-    seeds: 79 14 55 13
-    
-    seed-to-soil map:
-    50 98 2
-    52 50 48
-    
-    soil-to-fertilizer map:
-    0 15 37
-    37 52 2
-    39 0 15
-    
-    fertilizer-to-water map:
-    49 53 8
-    0 11 42
-    42 0 7
-    57 7 4
-    
-    water-to-light map:
-    88 18 7
-    18 25 70
-    
-    light-to-temperature map:
-    45 77 23
-    81 45 19
-    68 64 13
-    
-    temperature-to-humidity map:
-    0 69 1
-    1 0 69
-    
-    humidity-to-location map:
-    60 56 37
-    56 93 4
-    ";
-    assert_eq!(35, get_lowest_location(get_almanac(test_input.lines())));
-}
+aoc::example_tests!(|input: &str| get_lowest_location(get_almanac(input.lines())));
@@ -1,9 +1,14 @@
+use aoc::timing::timed;
 use std::{io, str::Lines};
 
+aoc::count_allocations!();
+
 fn main() -> io::Result<()> {
     let raw_input = io::read_to_string(io::stdin())?;
-    let input = parse_input(raw_input.lines());
-    print!("{}", get_result(&input));
+    let input = timed("parse", || parse_input(raw_input.lines()));
+    print!("{}", timed("solve", || get_result(&input)));
+
+    aoc::alloc::report();
 
     return Ok(());
 }
@@ -75,3 +80,5 @@ fn test_winnable_ways() -> () {
         })
     );
 }
+
+aoc::example_tests!(|input: &str| get_result(&parse_input(input.lines())));
@@ -1,11 +1,16 @@
+use aoc::timing::timed;
 use std::{io, ops::Mul, str::Lines};
 
 use itertools::Itertools;
 
+aoc::count_allocations!();
+
 fn main() -> io::Result<()> {
     let raw_input = io::read_to_string(io::stdin())?;
-    let input = parse_input(raw_input.lines());
-    print!("{}", get_result(&input));
+    let input = timed("parse", || parse_input(raw_input.lines()));
+    print!("{}", timed("solve", || get_result(&input)));
+
+    aoc::alloc::report();
 
     return Ok(());
 }
@@ -190,8 +195,4 @@ fn test_parse_input() -> () {
     )
 }
 
-#[test]
-fn test_get_result() -> () {
-    let input = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483";
-    assert_eq!(6440, get_result(&parse_input(input.lines())));
-}
+aoc::example_tests!(|input: &str| get_result(&parse_input(input.lines())));
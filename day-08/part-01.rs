@@ -1,9 +1,14 @@
+use aoc::timing::timed;
 use std::{collections::HashMap, io, str::Lines};
 
+aoc::count_allocations!();
+
 fn main() -> io::Result<()> {
     let raw_input = io::read_to_string(io::stdin())?;
-    let input = parse_input(raw_input.lines());
-    print!("{}", get_result(&input));
+    let input = timed("parse", || parse_input(raw_input.lines()));
+    print!("{}", timed("solve", || get_result(&input)));
+
+    aoc::alloc::report();
 
     return Ok(());
 }
@@ -88,14 +93,4 @@ fn test_parse_input() -> () {
     )
 }
 
-#[test]
-fn test_get_result_1() -> () {
-    let input = "RL\n\nAAA = (BBB, CCC)\nBBB = (DDD, EEE)\nCCC = (ZZZ, GGG)\nDDD = (DDD, DDD)\nEEE = (EEE, EEE)\nGGG = (GGG, GGG)\nZZZ = (ZZZ, ZZZ)";
-    assert_eq!(2, get_result(&parse_input(input.lines())));
-}
-
-#[test]
-fn test_get_result_2() -> () {
-    let input = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)";
-    assert_eq!(6, get_result(&parse_input(input.lines())));
-}
+aoc::example_tests!(|input: &str| get_result(&parse_input(input.lines())));
@@ -1,10 +1,15 @@
+use aoc::timing::timed;
 use num::integer::lcm;
 use std::{collections::HashMap, io, str::Lines};
 
+aoc::count_allocations!();
+
 fn main() -> io::Result<()> {
     let raw_input = io::read_to_string(io::stdin())?;
-    let input = parse_input(raw_input.lines());
-    print!("{}", get_result(&input));
+    let input = timed("parse", || parse_input(raw_input.lines()));
+    print!("{}", timed("solve", || get_result(&input)));
+
+    aoc::alloc::report();
 
     return Ok(());
 }
@@ -72,8 +77,4 @@ fn parse_line(line: &str) -> (String, (String, String)) {
     return (String::from(key), (left, right));
 }
 
-#[test]
-fn test_get_result_1() -> () {
-    let input = "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n22A = (22B, XXX)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)\nXXX = (XXX, XXX)\n";
-    assert_eq!(6, get_result(&parse_input(input.lines())));
-}
+aoc::example_tests!(|input: &str| get_result(&parse_input(input.lines())));
@@ -1,3 +1,4 @@
+use aoc::timing::timed;
 use std::io;
 
 use itertools::Itertools;
@@ -7,10 +8,14 @@ struct Input {
     histories: Vec<Vec<i64>>,
 }
 
+aoc::count_allocations!();
+
 fn main() -> io::Result<()> {
     let raw_input = io::read_to_string(io::stdin())?;
-    let input = parse_input(raw_input);
-    print!("{}", get_result(&input));
+    let input = timed("parse", || parse_input(raw_input));
+    print!("{}", timed("solve", || get_result(&input)));
+
+    aoc::alloc::report();
 
     return Ok(());
 }
@@ -74,13 +79,9 @@ fn test_parse_input() -> () {
     )
 }
 
-#[test]
-fn test_get_result() -> () {
-    let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
-    assert_eq!(114, get_result(&parse_input(input.to_string())));
-}
-
 #[test]
 fn test_next_seq() -> () {
     assert_eq!(vec![3, 3, 3, 3, 3], next_seq(&vec![0, 3, 6, 9, 12, 15]));
 }
+
+aoc::example_tests!(|input: &str| get_result(&parse_input(input.to_string())));
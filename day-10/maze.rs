@@ -0,0 +1,84 @@
+use aoc::grid::{Direction, Grid, Position};
+use std::str::Lines;
+
+use Direction::{East, North, South, West};
+
+const TILES: [(char, [Direction; 2]); 6] = [
+    ('|', [North, South]),
+    ('-', [East, West]),
+    ('L', [North, East]),
+    ('J', [North, West]),
+    ('7', [South, West]),
+    ('F', [South, East]),
+];
+
+pub struct Maze {
+    pub grid: Grid<char>,
+    pub start: Position,
+}
+
+fn connections(tile: char) -> &'static [Direction] {
+    return TILES
+        .iter()
+        .find(|(c, _)| *c == tile)
+        .map(|(_, directions)| &directions[..])
+        .unwrap_or(&[]);
+}
+
+pub fn connects(tile: char, direction: Direction) -> bool {
+    return connections(tile).contains(&direction);
+}
+
+impl Maze {
+    pub fn parse(lines: Lines) -> Maze {
+        let mut grid = Grid::from_lines(lines, |c| c);
+        let (start, _) = grid.iter().find(|(_, c)| **c == 'S').unwrap();
+        let tile = infer_tile(&grid, start).unwrap();
+        grid.set(start, tile);
+        return Maze { grid, start };
+    }
+
+    /// Positions of the loop through `S`, in walking order starting at `S`.
+    pub fn main_loop(&self) -> Vec<Position> {
+        let mut path = vec![self.start];
+        let mut direction = connections(self.grid[self.start])[0];
+        let mut position = self.start;
+        loop {
+            position = self.grid.step_towards(position, direction).unwrap();
+            if position == self.start {
+                return path;
+            }
+            path.push(position);
+            let back = direction.reverse();
+            direction = *connections(self.grid[position])
+                .iter()
+                .find(|d| **d != back)
+                .unwrap();
+        }
+    }
+}
+
+fn infer_tile(grid: &Grid<char>, start: Position) -> Option<char> {
+    let open: Vec<Direction> = Direction::ALL
+        .into_iter()
+        .filter(|direction| {
+            grid.step_towards(start, *direction)
+                .is_some_and(|next| connects(grid[next], direction.reverse()))
+        })
+        .collect();
+    return TILES
+        .iter()
+        .find(|(_, directions)| directions.iter().all(|d| open.contains(d)))
+        .map(|(c, _)| *c);
+}
+
+#[test]
+fn test_infer_tile() -> () {
+    let maze = Maze::parse(".....\n.S-7.\n.|.|.\n.L-J.\n.....".lines());
+    assert_eq!((1, 1), maze.start);
+    assert_eq!('F', maze.grid[maze.start]);
+
+    let maze = Maze::parse("..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...".lines());
+    assert_eq!('F', maze.grid[maze.start]);
+    assert_eq!(16, maze.main_loop().len());
+}
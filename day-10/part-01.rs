@@ -0,0 +1,31 @@
+mod maze;
+
+use aoc::timing::timed;
+use maze::Maze;
+use std::io;
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let input = io::read_to_string(io::stdin())?;
+    let maze = timed("parse", || Maze::parse(input.lines()));
+    print!("{}", timed("solve", || get_result(&maze)));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+fn get_result(maze: &Maze) -> usize {
+    return maze.main_loop().len() / 2;
+}
+
+#[test]
+fn test_get_result() -> () {
+    assert_eq!(
+        4,
+        get_result(&Maze::parse("-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF".lines()))
+    );
+}
+
+aoc::example_tests!(|input: &str| get_result(&Maze::parse(input.lines())));
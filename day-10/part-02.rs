@@ -0,0 +1,79 @@
+mod maze;
+
+use aoc::geometry::{interior_points, Point};
+#[cfg(test)]
+use aoc::grid::Direction;
+use aoc::timing::timed;
+#[cfg(test)]
+use maze::connects;
+use maze::Maze;
+use std::io;
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let input = io::read_to_string(io::stdin())?;
+    let maze = timed("parse", || Maze::parse(input.lines()));
+    print!("{}", timed("solve", || get_result(&maze)));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+/// Counts enclosed tiles from the loop's area (shoelace formula) and Pick's
+/// theorem.
+fn get_result(maze: &Maze) -> usize {
+    let vertices: Vec<Point> = maze
+        .main_loop()
+        .iter()
+        .map(|(row, col)| (*row as i64, *col as i64))
+        .collect();
+    return interior_points(&vertices) as usize;
+}
+
+/// Counts enclosed tiles by scanning each row and flipping inside/outside
+/// whenever a loop tile connects north.
+#[cfg(test)]
+fn enclosed_by_scanline(maze: &Maze) -> usize {
+    let mut on_loop = maze.grid.map(|_| false);
+    for position in maze.main_loop() {
+        on_loop.set(position, true);
+    }
+
+    let mut enclosed = 0;
+    for row in 0..maze.grid.height() {
+        let mut inside = false;
+        for col in 0..maze.grid.width() {
+            let position = (row, col);
+            if on_loop[position] {
+                if connects(maze.grid[position], Direction::North) {
+                    inside = !inside;
+                }
+            } else if inside {
+                enclosed += 1;
+            }
+        }
+    }
+    return enclosed;
+}
+
+#[test]
+fn test_get_result() -> () {
+    assert_eq!(
+        1,
+        get_result(&Maze::parse(".....\n.S-7.\n.|.|.\n.L-J.\n.....".lines()))
+    );
+}
+
+#[test]
+fn test_enclosed_methods_agree() -> () {
+    for name in [
+        "square", "complex", "enclosed", "larger", "junk", "squeezed",
+    ] {
+        let maze = Maze::parse(aoc::example!(name).lines());
+        assert_eq!(enclosed_by_scanline(&maze), get_result(&maze), "{}", name);
+    }
+}
+
+aoc::example_tests!(|input: &str| get_result(&Maze::parse(input.lines())));
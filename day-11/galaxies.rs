@@ -0,0 +1,60 @@
+use aoc::grid::{Grid, Position};
+use std::str::Lines;
+
+pub fn parse_galaxies(lines: Lines) -> Vec<Position> {
+    return Grid::from_lines(lines, |c| c)
+        .iter()
+        .filter(|(_, c)| **c == '#')
+        .map(|(position, _)| position)
+        .collect();
+}
+
+/// Sum of Manhattan distances over all galaxy pairs after every empty row and
+/// column is replaced by `factor` copies of itself. Each axis is handled
+/// independently with sorted prefix sums, so this is O(n log n) in galaxies.
+pub fn total_distance(galaxies: &[Position], factor: u64) -> u64 {
+    let rows: Vec<usize> = galaxies.iter().map(|(row, _)| *row).collect();
+    let cols: Vec<usize> = galaxies.iter().map(|(_, col)| *col).collect();
+    return axis_distance(&rows, factor) + axis_distance(&cols, factor);
+}
+
+fn axis_distance(coords: &[usize], factor: u64) -> u64 {
+    let mut expanded = expand(coords, factor);
+    expanded.sort();
+
+    let mut total = 0;
+    let mut prefix = 0;
+    for (index, coord) in expanded.iter().enumerate() {
+        total += coord * index as u64 - prefix;
+        prefix += coord;
+    }
+    return total;
+}
+
+fn expand(coords: &[usize], factor: u64) -> Vec<u64> {
+    let size = coords.iter().max().map_or(0, |max| max + 1);
+    let mut occupied = vec![false; size];
+    for coord in coords {
+        occupied[*coord] = true;
+    }
+
+    let mut empty_before = Vec::with_capacity(size);
+    let mut empty = 0;
+    for is_occupied in &occupied {
+        empty_before.push(empty);
+        if !is_occupied {
+            empty += 1;
+        }
+    }
+
+    return coords
+        .iter()
+        .map(|coord| *coord as u64 + empty_before[*coord] * (factor - 1))
+        .collect();
+}
+
+#[test]
+fn test_expand() -> () {
+    assert_eq!(vec![0, 3, 4, 7], expand(&[0, 2, 3, 5], 2));
+    assert_eq!(vec![0, 11, 12, 23], expand(&[0, 2, 3, 5], 10));
+}
@@ -0,0 +1,27 @@
+mod galaxies;
+
+use aoc::args::number_flag;
+use aoc::grid::Position;
+use aoc::timing::timed;
+use galaxies::{parse_galaxies, total_distance};
+use std::{env, io};
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let factor = number_flag(&args, "--factor", 1)?.unwrap_or(2);
+    let input = io::read_to_string(io::stdin())?;
+    let galaxies = timed("parse", || parse_galaxies(input.lines()));
+    print!("{}", timed("solve", || get_result(&galaxies, factor)));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+fn get_result(galaxies: &[Position], factor: u64) -> u64 {
+    return total_distance(galaxies, factor);
+}
+
+aoc::example_tests!(|input: &str| get_result(&parse_galaxies(input.lines()), 2));
@@ -0,0 +1,34 @@
+mod galaxies;
+
+use aoc::args::number_flag;
+use aoc::grid::Position;
+use aoc::timing::timed;
+use galaxies::{parse_galaxies, total_distance};
+use std::{env, io};
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let factor = number_flag(&args, "--factor", 1)?.unwrap_or(1_000_000);
+    let input = io::read_to_string(io::stdin())?;
+    let galaxies = timed("parse", || parse_galaxies(input.lines()));
+    print!("{}", timed("solve", || get_result(&galaxies, factor)));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+fn get_result(galaxies: &[Position], factor: u64) -> u64 {
+    return total_distance(galaxies, factor);
+}
+
+#[test]
+fn test_get_result() -> () {
+    let galaxies = parse_galaxies(aoc::example!("example").lines());
+    assert_eq!(1030, get_result(&galaxies, 10));
+    assert_eq!(8410, get_result(&galaxies, 100));
+}
+
+aoc::example_tests!(|input: &str| get_result(&parse_galaxies(input.lines()), 1_000_000));
@@ -0,0 +1,26 @@
+mod springs;
+
+use aoc::args::number_flag;
+use aoc::timing::timed;
+use springs::{parse_input, total_arrangements, Row};
+use std::{env, io};
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let factor = number_flag(&args, "--unfold", 1)?.unwrap_or(1);
+    let input = io::read_to_string(io::stdin())?;
+    let rows = timed("parse", || parse_input(input.lines()))?;
+    print!("{}", timed("solve", || get_result(&rows, factor)));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+fn get_result(rows: &[Row], factor: usize) -> u64 {
+    return total_arrangements(rows, factor);
+}
+
+aoc::example_tests!(|input: &str| get_result(&parse_input(input.lines()).unwrap(), 1));
@@ -0,0 +1,184 @@
+use std::io;
+use std::str::Lines;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Row {
+    pub springs: Vec<char>,
+    pub groups: Vec<usize>,
+}
+
+impl Row {
+    pub fn unfold(&self, factor: usize) -> Row {
+        let mut springs = vec![];
+        for copy in 0..factor {
+            if copy > 0 {
+                springs.push('?');
+            }
+            springs.extend(&self.springs);
+        }
+        return Row {
+            springs,
+            groups: self.groups.repeat(factor),
+        };
+    }
+
+    /// Counts arrangements with a table over (spring index, group index),
+    /// filled from the end of the row backwards.
+    pub fn arrangements(&self) -> u64 {
+        let springs = &self.springs;
+        let groups = &self.groups;
+        let n = springs.len();
+        let m = groups.len();
+
+        // ways[i][j]: arrangements of springs[i..] matching groups[j..]
+        let mut ways = vec![vec![0u64; m + 1]; n + 2];
+        ways[n][m] = 1;
+        ways[n + 1][m] = 1;
+        for i in (0..n).rev() {
+            for j in 0..=m {
+                let mut count = 0;
+                if springs[i] != '#' {
+                    count += ways[i + 1][j];
+                }
+                if springs[i] != '.' && j < m {
+                    let end = i + groups[j];
+                    if end <= n
+                        && !springs[i..end].contains(&'.')
+                        && (end == n || springs[end] != '#')
+                    {
+                        count += ways[end + 1][j + 1];
+                    }
+                }
+                ways[i][j] = count;
+            }
+        }
+        return ways[0][0];
+    }
+}
+
+pub fn parse_row(line: &str) -> Option<Row> {
+    let (springs, groups) = line.split_once(' ')?;
+    return Some(Row {
+        springs: springs.chars().collect(),
+        groups: groups
+            .split(',')
+            .map(|group| group.parse().ok())
+            .collect::<Option<Vec<usize>>>()?,
+    });
+}
+
+pub fn parse_input(lines: Lines) -> io::Result<Vec<Row>> {
+    return lines
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(line_index, line)| {
+            parse_row(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: invalid row: {:?}", line_index + 1, line),
+                )
+            })
+        })
+        .collect();
+}
+
+pub fn total_arrangements(rows: &[Row], factor: usize) -> u64 {
+    return rows
+        .iter()
+        .map(|row| row.unfold(factor).arrangements())
+        .sum();
+}
+
+#[cfg(test)]
+fn brute_force(row: &Row) -> u64 {
+    let unknowns: Vec<usize> = (0..row.springs.len())
+        .filter(|index| row.springs[*index] == '?')
+        .collect();
+    let mut count = 0;
+    for mask in 0..1u64 << unknowns.len() {
+        let mut springs = row.springs.clone();
+        for (bit, index) in unknowns.iter().enumerate() {
+            springs[*index] = if mask >> bit & 1 == 1 { '#' } else { '.' };
+        }
+        let groups: Vec<usize> = springs
+            .split(|c| *c == '.')
+            .map(|run| run.len())
+            .filter(|len| *len > 0)
+            .collect();
+        if groups == row.groups {
+            count += 1;
+        }
+    }
+    return count;
+}
+
+#[test]
+fn test_parse_row() -> () {
+    assert_eq!(
+        Some(Row {
+            springs: vec!['?', '#', '.'],
+            groups: vec![1, 2],
+        }),
+        parse_row("?#. 1,2")
+    );
+    assert_eq!(None, parse_row("?#."));
+    assert_eq!(None, parse_row("?#. 1,x"));
+    assert_eq!(
+        parse_row("???.###????.###????.### 1,1,3,1,1,3,1,1,3"),
+        parse_row("???.### 1,1,3").map(|row| row.unfold(3))
+    );
+}
+
+#[test]
+fn test_parse_input() -> () {
+    assert_eq!(2, parse_input("?#. 1\n\n.?# 1\n".lines()).unwrap().len());
+    let err = parse_input("?#. 1\n?###???????? 3,2,x".lines()).unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidData, err.kind());
+    assert_eq!(
+        "line 2: invalid row: \"?###???????? 3,2,x\"",
+        err.to_string()
+    );
+}
+
+#[test]
+fn test_arrangements() -> () {
+    let rows = parse_input(aoc::example!("example").lines()).unwrap();
+    assert_eq!(
+        vec![1, 4, 1, 1, 4, 10],
+        rows.iter()
+            .map(|row| row.arrangements())
+            .collect::<Vec<u64>>()
+    );
+    assert_eq!(
+        vec![1, 16384, 1, 16, 2500, 506250],
+        rows.iter()
+            .map(|row| row.unfold(5).arrangements())
+            .collect::<Vec<u64>>()
+    );
+}
+
+#[test]
+fn test_arrangements_match_brute_force() -> () {
+    for row in parse_input(aoc::example!("example").lines()).unwrap() {
+        for factor in [1, 2] {
+            let row = row.unfold(factor);
+            if row.springs.iter().filter(|c| **c == '?').count() <= 16 {
+                assert_eq!(brute_force(&row), row.arrangements(), "{:?}", row);
+            }
+        }
+    }
+
+    let groups_options = [vec![], vec![1], vec![2], vec![1, 1], vec![1, 2], vec![3, 1]];
+    for code in 0..3usize.pow(6) {
+        let springs: Vec<char> = (0..6)
+            .map(|digit| ['.', '#', '?'][code / 3usize.pow(digit) % 3])
+            .collect();
+        for groups in &groups_options {
+            let row = Row {
+                springs: springs.clone(),
+                groups: groups.clone(),
+            };
+            assert_eq!(brute_force(&row), row.arrangements(), "{:?}", row);
+        }
+    }
+}
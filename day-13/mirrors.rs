@@ -0,0 +1,113 @@
+use std::str::Lines;
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Pattern {
+    pub rows: Vec<u64>,
+    pub columns: Vec<u64>,
+}
+
+pub fn parse_patterns(lines: Lines) -> Vec<Pattern> {
+    let mut blocks: Vec<Vec<&str>> = vec![vec![]];
+    for line in lines {
+        if line.trim().is_empty() {
+            blocks.push(vec![]);
+            continue;
+        }
+        blocks.last_mut().unwrap().push(line.trim());
+    }
+
+    return blocks
+        .iter()
+        .filter(|block| !block.is_empty())
+        .map(|block| parse_pattern(block))
+        .collect();
+}
+
+fn parse_pattern(block: &[&str]) -> Pattern {
+    let width = block[0].len();
+    let mut rows = vec![0; block.len()];
+    let mut columns = vec![0; width];
+    for (row, line) in block.iter().enumerate() {
+        for (col, c) in line.chars().enumerate() {
+            if c == '#' {
+                rows[row] |= 1 << col;
+                columns[col] |= 1 << row;
+            }
+        }
+    }
+    return Pattern { rows, columns };
+}
+
+/// Finds the mirror line with exactly `smudges` mismatching cells across it,
+/// returned as the number of lines before it.
+fn reflection(lines: &[u64], smudges: u32) -> Option<usize> {
+    return (1..lines.len()).find(|split| {
+        lines[..*split]
+            .iter()
+            .rev()
+            .zip(&lines[*split..])
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum::<u32>()
+            == smudges
+    });
+}
+
+impl Pattern {
+    pub fn summary(&self, smudges: u32) -> usize {
+        if let Some(columns) = reflection(&self.columns, smudges) {
+            return columns;
+        }
+        return reflection(&self.rows, smudges).map_or(0, |rows| rows * 100);
+    }
+}
+
+pub fn total_summary(patterns: &[Pattern], smudges: u32) -> usize {
+    return patterns
+        .iter()
+        .map(|pattern| pattern.summary(smudges))
+        .sum();
+}
+
+#[test]
+fn test_parse_patterns() -> () {
+    assert_eq!(
+        vec![
+            Pattern {
+                rows: vec![0b01, 0b10],
+                columns: vec![0b01, 0b10],
+            },
+            Pattern {
+                rows: vec![0b11],
+                columns: vec![0b1, 0b1],
+            },
+        ],
+        parse_patterns("#.\n.#\n\n\n##\n".lines())
+    );
+}
+
+#[test]
+fn test_reflection() -> () {
+    assert_eq!(Some(2), reflection(&[1, 2, 2, 1, 5], 0));
+    assert_eq!(None, reflection(&[1, 2, 3], 0));
+    assert_eq!(Some(1), reflection(&[1, 3, 7], 1));
+    assert_eq!(Some(2), reflection(&[1, 2, 3, 1], 1));
+}
+
+#[test]
+fn test_summary() -> () {
+    let patterns = parse_patterns(aoc::example!("example").lines());
+    assert_eq!(
+        vec![5, 400],
+        patterns
+            .iter()
+            .map(|p| p.summary(0))
+            .collect::<Vec<usize>>()
+    );
+    assert_eq!(
+        vec![300, 100],
+        patterns
+            .iter()
+            .map(|p| p.summary(1))
+            .collect::<Vec<usize>>()
+    );
+}
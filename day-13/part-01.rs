@@ -0,0 +1,23 @@
+mod mirrors;
+
+use aoc::timing::timed;
+use mirrors::{parse_patterns, total_summary, Pattern};
+use std::io;
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let input = io::read_to_string(io::stdin())?;
+    let patterns = timed("parse", || parse_patterns(input.lines()));
+    print!("{}", timed("solve", || get_result(&patterns)));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+fn get_result(patterns: &[Pattern]) -> usize {
+    return total_summary(patterns, 0);
+}
+
+aoc::example_tests!(|input: &str| get_result(&parse_patterns(input.lines())));
@@ -0,0 +1,59 @@
+use aoc::grid::{Direction, Grid, Position};
+use std::iter;
+
+/// Lines of positions running against `direction`, each starting at the edge
+/// the rocks roll towards.
+pub fn lanes(grid: &Grid<char>, direction: Direction) -> Vec<Vec<Position>> {
+    return grid
+        .positions()
+        .filter(|position| grid.step_towards(*position, direction).is_none())
+        .map(|edge| {
+            iter::successors(Some(edge), |position| {
+                grid.step_towards(*position, direction.reverse())
+            })
+            .collect()
+        })
+        .collect();
+}
+
+/// Rolls every round rock along `lanes`, as built by [`lanes`], towards the
+/// start of its lane.
+pub fn tilt(grid: &mut Grid<char>, lanes: &[Vec<Position>]) {
+    for lane in lanes {
+        let mut free = 0;
+        for (index, position) in lane.iter().enumerate() {
+            match grid[*position] {
+                '#' => free = index + 1,
+                'O' => {
+                    grid[*position] = '.';
+                    grid[lane[free]] = 'O';
+                    free += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+pub fn north_load(grid: &Grid<char>) -> usize {
+    return grid
+        .iter()
+        .filter(|(_, c)| **c == 'O')
+        .map(|((row, _), _)| grid.height() - row)
+        .sum();
+}
+
+#[test]
+fn test_tilt() -> () {
+    let mut grid = Grid::parse("O.#O.\n..O..\nO...O");
+    for (direction, expected) in [
+        (Direction::North, "O.#OO\nO.O..\n.....\n"),
+        (Direction::East, ".O#OO\n...OO\n.....\n"),
+        (Direction::South, "..#..\n...OO\n.O.OO\n"),
+        (Direction::West, "..#..\nOO...\nOOO..\n"),
+    ] {
+        let lanes = lanes(&grid, direction);
+        tilt(&mut grid, &lanes);
+        assert_eq!(expected, grid.to_string());
+    }
+}
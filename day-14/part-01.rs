@@ -0,0 +1,26 @@
+mod dish;
+
+use aoc::grid::{Direction, Grid};
+use aoc::timing::timed;
+use dish::{lanes, north_load, tilt};
+use std::io;
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let input = io::read_to_string(io::stdin())?;
+    let grid = timed("parse", || Grid::parse(&input));
+    print!("{}", timed("solve", || get_result(grid)));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+fn get_result(mut grid: Grid<char>) -> usize {
+    let lanes = lanes(&grid, Direction::North);
+    tilt(&mut grid, &lanes);
+    return north_load(&grid);
+}
+
+aoc::example_tests!(|input: &str| get_result(Grid::parse(input)));
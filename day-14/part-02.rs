@@ -0,0 +1,102 @@
+mod dish;
+
+use aoc::grid::{Direction, Grid, Position};
+use aoc::timing::timed;
+use dish::{lanes, north_load, tilt};
+use std::collections::HashMap;
+use std::io;
+
+aoc::count_allocations!();
+
+const CYCLES: usize = 1_000_000_000;
+
+const SPIN: [Direction; 4] = [
+    Direction::North,
+    Direction::West,
+    Direction::South,
+    Direction::East,
+];
+
+fn main() -> io::Result<()> {
+    let input = io::read_to_string(io::stdin())?;
+    let grid = timed("parse", || Grid::parse(&input));
+    print!("{}", timed("solve", || get_result(&grid)));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+fn get_result(grid: &Grid<char>) -> usize {
+    return north_load(&spin(grid, CYCLES));
+}
+
+fn spin_cycle(grid: &mut Grid<char>, lanes: &[Vec<Vec<Position>>; 4]) {
+    for lanes in lanes {
+        tilt(grid, lanes);
+    }
+}
+
+/// Runs `cycles` spin cycles, skipping ahead once a previously seen state
+/// repeats.
+fn spin(grid: &Grid<char>, cycles: usize) -> Grid<char> {
+    let mut seen: HashMap<Grid<char>, usize> = HashMap::new();
+    let lanes = SPIN.map(|direction| lanes(grid, direction));
+    let mut grid = grid.clone();
+    let mut cycle = 0;
+    while cycle < cycles {
+        if let Some(start) = seen.get(&grid) {
+            let period = cycle - start;
+            for _ in 0..(cycles - cycle) % period {
+                spin_cycle(&mut grid, &lanes);
+            }
+            return grid;
+        }
+        seen.insert(grid.clone(), cycle);
+        spin_cycle(&mut grid, &lanes);
+        cycle += 1;
+    }
+    return grid;
+}
+
+#[test]
+fn test_spin() -> () {
+    let grid = Grid::parse(
+        "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....",
+    );
+    let lanes = SPIN.map(|direction| lanes(&grid, direction));
+    let mut spun = grid.clone();
+    spin_cycle(&mut spun, &lanes);
+    assert_eq!(
+        ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....
+",
+        spun.to_string()
+    );
+    for cycles in [5, 17, 100] {
+        let mut expected = grid.clone();
+        for _ in 0..cycles {
+            spin_cycle(&mut expected, &lanes);
+        }
+        assert_eq!(expected, spin(&grid, cycles));
+    }
+}
+
+aoc::example_tests!(|input: &str| get_result(&Grid::parse(input)));
@@ -0,0 +1,19 @@
+pub fn hash(text: &str) -> u8 {
+    return text
+        .bytes()
+        .fold(0u8, |acc, byte| acc.wrapping_add(byte).wrapping_mul(17));
+}
+
+pub fn split_steps(input: &str) -> impl Iterator<Item = &str> {
+    return input
+        .split(',')
+        .map(|step| step.trim_matches(|c: char| c.is_whitespace()))
+        .filter(|step| !step.is_empty());
+}
+
+#[test]
+fn test_hash() -> () {
+    assert_eq!(52, hash("HASH"));
+    assert_eq!(0, hash("rn"));
+    assert_eq!(3, hash("pc"));
+}
@@ -0,0 +1,164 @@
+use crate::hash::{hash, split_steps};
+use std::fmt;
+
+#[derive(Eq, PartialEq, Debug)]
+pub enum Operation {
+    Remove,
+    Insert(u8),
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Step {
+    pub label: String,
+    pub operation: Operation,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub enum StepError {
+    InvalidLabel(String),
+    MissingOperation(String),
+    InvalidFocalLength(String),
+}
+
+impl fmt::Display for StepError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            StepError::InvalidLabel(step) => write!(f, "invalid label in step {:?}", step),
+            StepError::MissingOperation(step) => {
+                write!(f, "step {:?} has no '-' or '=' operation", step)
+            }
+            StepError::InvalidFocalLength(step) => {
+                write!(f, "invalid focal length in step {:?}", step)
+            }
+        };
+    }
+}
+
+pub fn parse_step(step: &str) -> Result<Step, StepError> {
+    let (label, operation) = if let Some((label, focal_length)) = step.split_once('=') {
+        let focal_length = focal_length
+            .parse::<u8>()
+            .ok()
+            .filter(|focal_length| (1..=9).contains(focal_length))
+            .ok_or_else(|| StepError::InvalidFocalLength(step.to_string()))?;
+        (label, Operation::Insert(focal_length))
+    } else if let Some(label) = step.strip_suffix('-') {
+        (label, Operation::Remove)
+    } else {
+        return Err(StepError::MissingOperation(step.to_string()));
+    };
+
+    if label.is_empty() || !label.chars().all(|c| c.is_ascii_lowercase()) {
+        return Err(StepError::InvalidLabel(step.to_string()));
+    }
+    return Ok(Step {
+        label: label.to_string(),
+        operation,
+    });
+}
+
+pub fn parse_steps(input: &str) -> Result<Vec<Step>, StepError> {
+    return split_steps(input).map(parse_step).collect();
+}
+
+pub struct Boxes {
+    boxes: Vec<Vec<(String, u8)>>,
+}
+
+impl Boxes {
+    pub fn new() -> Boxes {
+        return Boxes {
+            boxes: vec![vec![]; 256],
+        };
+    }
+
+    pub fn apply(&mut self, step: &Step) {
+        let lenses = &mut self.boxes[hash(&step.label) as usize];
+        let slot = lenses.iter().position(|(label, _)| *label == step.label);
+        match (&step.operation, slot) {
+            (Operation::Remove, Some(slot)) => {
+                lenses.remove(slot);
+            }
+            (Operation::Remove, None) => {}
+            (Operation::Insert(focal_length), Some(slot)) => lenses[slot].1 = *focal_length,
+            (Operation::Insert(focal_length), None) => {
+                lenses.push((step.label.clone(), *focal_length))
+            }
+        }
+    }
+
+    pub fn focusing_power(&self) -> usize {
+        return self
+            .boxes
+            .iter()
+            .enumerate()
+            .flat_map(|(box_index, lenses)| {
+                lenses
+                    .iter()
+                    .enumerate()
+                    .map(move |(slot, (_, focal_length))| {
+                        (box_index + 1) * (slot + 1) * *focal_length as usize
+                    })
+            })
+            .sum();
+    }
+}
+
+#[test]
+fn test_parse_step() -> () {
+    assert_eq!(
+        Ok(Step {
+            label: "rn".to_string(),
+            operation: Operation::Insert(1),
+        }),
+        parse_step("rn=1")
+    );
+    assert_eq!(
+        Ok(Step {
+            label: "cm".to_string(),
+            operation: Operation::Remove,
+        }),
+        parse_step("cm-")
+    );
+    assert_eq!(
+        Err(StepError::MissingOperation("cm".to_string())),
+        parse_step("cm")
+    );
+    assert_eq!(
+        Err(StepError::InvalidFocalLength("qp=x".to_string())),
+        parse_step("qp=x")
+    );
+    assert_eq!(
+        Err(StepError::InvalidFocalLength("qp=10".to_string())),
+        parse_step("qp=10")
+    );
+    assert_eq!(
+        Err(StepError::InvalidLabel("=3".to_string())),
+        parse_step("=3")
+    );
+    assert_eq!(
+        Err(StepError::InvalidLabel("a-b-".to_string())),
+        parse_step("a-b-")
+    );
+}
+
+#[test]
+fn test_boxes() -> () {
+    let mut boxes = Boxes::new();
+    for step in parse_steps("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n").unwrap() {
+        boxes.apply(&step);
+    }
+    assert_eq!(
+        vec![("rn".to_string(), 1), ("cm".to_string(), 2)],
+        boxes.boxes[0]
+    );
+    assert_eq!(
+        vec![
+            ("ot".to_string(), 7),
+            ("ab".to_string(), 5),
+            ("pc".to_string(), 6)
+        ],
+        boxes.boxes[3]
+    );
+    assert_eq!(145, boxes.focusing_power());
+}
@@ -0,0 +1,27 @@
+mod hash;
+
+use aoc::timing::timed;
+use hash::{hash, split_steps};
+use std::io;
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let input = io::read_to_string(io::stdin())?;
+    print!("{}", timed("solve", || get_result(&input)));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+fn get_result(input: &str) -> u32 {
+    return split_steps(input).map(|step| hash(step) as u32).sum();
+}
+
+#[test]
+fn test_get_result() -> () {
+    assert_eq!(52, get_result("HASH\n"));
+}
+
+aoc::example_tests!(|input: &str| get_result(input));
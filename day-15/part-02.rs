@@ -0,0 +1,29 @@
+mod hash;
+mod lens;
+
+use aoc::timing::timed;
+use lens::{parse_steps, Boxes, Step};
+use std::io;
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let input = io::read_to_string(io::stdin())?;
+    let steps = timed("parse", || parse_steps(&input))
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+    print!("{}", timed("solve", || get_result(&steps)));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+fn get_result(steps: &[Step]) -> usize {
+    let mut boxes = Boxes::new();
+    for step in steps {
+        boxes.apply(step);
+    }
+    return boxes.focusing_power();
+}
+
+aoc::example_tests!(|input: &str| get_result(&parse_steps(input).unwrap()));
@@ -0,0 +1,59 @@
+use aoc::grid::{Direction, Grid, Position};
+use std::collections::HashSet;
+
+use Direction::{East, North, South, West};
+
+fn is_vertical(direction: Direction) -> bool {
+    return direction == North || direction == South;
+}
+
+fn deflect(tile: char, direction: Direction) -> Vec<Direction> {
+    return match tile {
+        '/' if is_vertical(direction) => vec![direction.turn_right()],
+        '/' => vec![direction.turn_left()],
+        '\\' if is_vertical(direction) => vec![direction.turn_left()],
+        '\\' => vec![direction.turn_right()],
+        '|' if !is_vertical(direction) => vec![North, South],
+        '-' if is_vertical(direction) => vec![East, West],
+        _ => vec![direction],
+    };
+}
+
+/// Follows the beam entering at `start` heading in `direction`, tracking
+/// visited `(position, direction)` states so loops terminate.
+pub fn energize(grid: &Grid<char>, start: Position, direction: Direction) -> Grid<bool> {
+    let mut energized = grid.map(|_| false);
+    let mut visited: HashSet<(Position, Direction)> = HashSet::new();
+    let mut beams = vec![(start, direction)];
+    while let Some((position, direction)) = beams.pop() {
+        if !visited.insert((position, direction)) {
+            continue;
+        }
+        energized[position] = true;
+        for next_direction in deflect(grid[position], direction) {
+            if let Some(next) = grid.step_towards(position, next_direction) {
+                beams.push((next, next_direction));
+            }
+        }
+    }
+    return energized;
+}
+
+pub fn energized_count(grid: &Grid<char>, start: Position, direction: Direction) -> usize {
+    return energize(grid, start, direction)
+        .iter()
+        .filter(|(_, energized)| **energized)
+        .count();
+}
+
+#[test]
+fn test_deflect() -> () {
+    assert_eq!(vec![North], deflect('/', East));
+    assert_eq!(vec![West], deflect('/', South));
+    assert_eq!(vec![South], deflect('\\', East));
+    assert_eq!(vec![East], deflect('\\', South));
+    assert_eq!(vec![North, South], deflect('|', West));
+    assert_eq!(vec![South], deflect('|', South));
+    assert_eq!(vec![East, West], deflect('-', North));
+    assert_eq!(vec![West], deflect('-', West));
+}
@@ -0,0 +1,51 @@
+mod beams;
+
+use aoc::grid::{Direction, Grid};
+use aoc::timing::timed;
+use beams::{energize, energized_count};
+use std::env;
+use std::io;
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let args: Vec<String> = env::args().collect();
+    let input = io::read_to_string(io::stdin())?;
+    let grid = timed("parse", || Grid::parse(&input));
+    print!("{}", timed("solve", || get_result(&grid)));
+
+    if args.iter().any(|a| a == "--render") {
+        eprint!(
+            "{}",
+            render(&grid, &energize(&grid, (0, 0), Direction::East))
+        );
+    }
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+fn get_result(grid: &Grid<char>) -> usize {
+    return energized_count(grid, (0, 0), Direction::East);
+}
+
+/// Draws energized tiles as `#`, leaving the rest of the layout visible.
+fn render(grid: &Grid<char>, energized: &Grid<bool>) -> String {
+    let mut cells = grid.clone();
+    for (position, is_energized) in energized.iter() {
+        if *is_energized {
+            cells[position] = '#';
+        }
+    }
+    return cells.to_string();
+}
+
+#[test]
+fn test_render() -> () {
+    let grid = Grid::parse(".\\.\n.-.\n...");
+    let energized = energize(&grid, (0, 0), Direction::East);
+    assert_eq!("##.\n###\n...\n", render(&grid, &energized));
+}
+
+aoc::example_tests!(|input: &str| get_result(&Grid::parse(input)));
@@ -0,0 +1,43 @@
+mod beams;
+
+use aoc::grid::{Direction, Grid, Position};
+use aoc::timing::timed;
+use beams::energized_count;
+use rayon::prelude::*;
+use std::io;
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let input = io::read_to_string(io::stdin())?;
+    let grid = timed("parse", || Grid::parse(&input));
+    print!("{}", timed("solve", || get_result(&grid)));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+fn get_result(grid: &Grid<char>) -> usize {
+    return edge_entries(grid)
+        .par_iter()
+        .map(|(start, direction)| energized_count(grid, *start, *direction))
+        .max()
+        .unwrap_or(0);
+}
+
+fn edge_entries(grid: &Grid<char>) -> Vec<(Position, Direction)> {
+    let (height, width) = (grid.height(), grid.width());
+    let mut entries = vec![];
+    for row in 0..height {
+        entries.push(((row, 0), Direction::East));
+        entries.push(((row, width - 1), Direction::West));
+    }
+    for col in 0..width {
+        entries.push(((0, col), Direction::South));
+        entries.push(((height - 1, col), Direction::North));
+    }
+    return entries;
+}
+
+aoc::example_tests!(|input: &str| get_result(&Grid::parse(input)));
@@ -0,0 +1,148 @@
+use aoc::grid::{Direction, Grid, Position};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+struct State {
+    position: Position,
+    direction: Direction,
+    run: usize,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Route {
+    pub heat_loss: u32,
+    pub path: Vec<Position>,
+}
+
+pub fn parse_map(input: &str) -> Grid<u32> {
+    return Grid::from_lines(input.lines(), |c| c.to_digit(10).unwrap());
+}
+
+/// Dijkstra over `(position, direction, run)` states, where a crucible must
+/// move at least `min_run` blocks before turning or stopping and at most
+/// `max_run` blocks in a straight line.
+pub fn find_route(map: &Grid<u32>, min_run: usize, max_run: usize) -> Option<Route> {
+    let target = (map.height() - 1, map.width() - 1);
+    let mut best: HashMap<State, u32> = HashMap::new();
+    let mut previous: HashMap<State, State> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for direction in [Direction::East, Direction::South] {
+        let state = State {
+            position: (0, 0),
+            direction,
+            run: 0,
+        };
+        best.insert(state, 0);
+        queue.push(Reverse((0, state)));
+    }
+
+    while let Some(Reverse((heat_loss, state))) = queue.pop() {
+        if best.get(&state).is_some_and(|best| heat_loss > *best) {
+            continue;
+        }
+        if state.position == target && state.run >= min_run {
+            return Some(Route {
+                heat_loss,
+                path: reconstruct(&previous, state),
+            });
+        }
+
+        for direction in [
+            state.direction,
+            state.direction.turn_right(),
+            state.direction.turn_left(),
+        ] {
+            let run = if direction == state.direction {
+                state.run + 1
+            } else {
+                1
+            };
+            if run > max_run || (direction != state.direction && state.run < min_run) {
+                continue;
+            }
+            let Some(position) = map.step_towards(state.position, direction) else {
+                continue;
+            };
+            let next = State {
+                position,
+                direction,
+                run,
+            };
+            let next_heat_loss = heat_loss + map[position];
+            if best.get(&next).is_none_or(|best| next_heat_loss < *best) {
+                best.insert(next, next_heat_loss);
+                previous.insert(next, state);
+                queue.push(Reverse((next_heat_loss, next)));
+            }
+        }
+    }
+    return None;
+}
+
+fn reconstruct(previous: &HashMap<State, State>, end: State) -> Vec<Position> {
+    let mut path = vec![end.position];
+    let mut state = end;
+    while let Some(prev) = previous.get(&state) {
+        path.push(prev.position);
+        state = *prev;
+    }
+    path.reverse();
+    return path;
+}
+
+pub fn render_path(map: &Grid<u32>, path: &[Position]) -> String {
+    let mut cells = map.map(|heat_loss| char::from_digit(*heat_loss, 10).unwrap());
+    for position in path {
+        cells[*position] = '#';
+    }
+    return cells.to_string();
+}
+
+#[test]
+fn test_find_route() -> () {
+    let map = parse_map("119\n919\n911");
+    let route = find_route(&map, 1, 3).unwrap();
+    assert_eq!(4, route.heat_loss);
+    assert_eq!(vec![(0, 0), (0, 1), (1, 1), (2, 1), (2, 2)], route.path);
+    assert_eq!("##9\n9#9\n9##\n", render_path(&map, &route.path));
+
+    let route = find_route(&map, 1, 2).unwrap();
+    assert_eq!(4, route.heat_loss);
+    assert_eq!(None, find_route(&map, 3, 3));
+}
+
+#[test]
+fn test_path_heat_loss() -> () {
+    let map = parse_map(
+        "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533",
+    );
+    for (min_run, max_run) in [(1, 3), (4, 10)] {
+        let route = find_route(&map, min_run, max_run).unwrap();
+        assert_eq!(
+            route.heat_loss,
+            route.path[1..]
+                .iter()
+                .map(|position| map[*position])
+                .sum::<u32>()
+        );
+        for pair in route.path.windows(2) {
+            assert_eq!(
+                1,
+                pair[0].0.abs_diff(pair[1].0) + pair[0].1.abs_diff(pair[1].1)
+            );
+        }
+    }
+}
@@ -0,0 +1,27 @@
+mod crucible;
+
+use aoc::timing::timed;
+use crucible::{find_route, parse_map, render_path};
+use std::env;
+use std::io;
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let args: Vec<String> = env::args().collect();
+    let input = io::read_to_string(io::stdin())?;
+    let map = timed("parse", || parse_map(&input));
+    let route = timed("solve", || find_route(&map, 1, 3))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "no route to the factory"))?;
+    print!("{}", route.heat_loss);
+
+    if args.iter().any(|a| a == "--path") {
+        eprint!("{}", render_path(&map, &route.path));
+    }
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+aoc::example_tests!(|input: &str| find_route(&parse_map(input), 1, 3).unwrap().heat_loss);
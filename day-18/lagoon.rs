@@ -0,0 +1,63 @@
+use aoc::geometry::{boundary_points, interior_points, Point};
+use std::io;
+use std::str::Lines;
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Instruction {
+    pub direction: (i64, i64),
+    pub distance: i64,
+}
+
+pub fn direction(code: &str) -> Option<(i64, i64)> {
+    return match code {
+        "U" | "3" => Some((-1, 0)),
+        "D" | "1" => Some((1, 0)),
+        "L" | "2" => Some((0, -1)),
+        "R" | "0" => Some((0, 1)),
+        _ => None,
+    };
+}
+
+pub fn parse_plan(
+    lines: Lines,
+    decode: fn(&str) -> Option<Instruction>,
+) -> io::Result<Vec<Instruction>> {
+    return lines
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(line_index, line)| {
+            decode(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: invalid instruction: {:?}", line_index + 1, line),
+                )
+            })
+        })
+        .collect();
+}
+
+/// Cubic metres of lava held: the trench itself plus every tile inside it.
+pub fn lagoon_volume(plan: &[Instruction]) -> i64 {
+    let mut vertices: Vec<Point> = vec![];
+    let mut position = (0, 0);
+    for instruction in plan {
+        position = (
+            position.0 + instruction.direction.0 * instruction.distance,
+            position.1 + instruction.direction.1 * instruction.distance,
+        );
+        vertices.push(position);
+    }
+    return interior_points(&vertices) + boundary_points(&vertices);
+}
+
+#[test]
+fn test_lagoon_volume() -> () {
+    let square: Vec<Instruction> = [(0, 1), (1, 0), (0, -1), (-1, 0)]
+        .into_iter()
+        .map(|direction| Instruction {
+            direction,
+            distance: 2,
+        })
+        .collect();
+    assert_eq!(9, lagoon_volume(&square));
+}
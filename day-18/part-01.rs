@@ -0,0 +1,51 @@
+mod lagoon;
+
+use aoc::timing::timed;
+use lagoon::{direction, lagoon_volume, parse_plan, Instruction};
+use std::io;
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let input = io::read_to_string(io::stdin())?;
+    let plan = timed("parse", || parse_plan(input.lines(), parse_instruction))?;
+    print!("{}", timed("solve", || lagoon_volume(&plan)));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+fn parse_instruction(line: &str) -> Option<Instruction> {
+    let mut parts = line.split_whitespace();
+    return Some(Instruction {
+        direction: direction(parts.next()?)?,
+        distance: parts.next()?.parse().ok()?,
+    });
+}
+
+#[test]
+fn test_parse_instruction() -> () {
+    assert_eq!(
+        Some(Instruction {
+            direction: (0, 1),
+            distance: 6,
+        }),
+        parse_instruction("R 6 (#70c710)")
+    );
+    assert_eq!(None, parse_instruction("X 6 (#70c710)"));
+}
+
+#[test]
+fn test_parse_plan() -> () {
+    let err = parse_plan("R 6 (#70c710)\nQ 5 (#0dc571)".lines(), parse_instruction).unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidData, err.kind());
+    assert_eq!(
+        "line 2: invalid instruction: \"Q 5 (#0dc571)\"",
+        err.to_string()
+    );
+}
+
+aoc::example_tests!(|input: &str| lagoon_volume(
+    &parse_plan(input.lines(), parse_instruction).unwrap()
+));
@@ -0,0 +1,55 @@
+mod lagoon;
+
+use aoc::timing::timed;
+use lagoon::{direction, lagoon_volume, parse_plan, Instruction};
+use std::io;
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let input = io::read_to_string(io::stdin())?;
+    let plan = timed("parse", || parse_plan(input.lines(), decode_hex))?;
+    print!("{}", timed("solve", || lagoon_volume(&plan)));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+/// Reads the instruction hidden in the colour: five hex digits of distance
+/// followed by one digit of direction.
+fn decode_hex(line: &str) -> Option<Instruction> {
+    let hex = line
+        .split_whitespace()
+        .nth(2)?
+        .strip_prefix("(#")?
+        .strip_suffix(')')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    return Some(Instruction {
+        direction: direction(&hex[5..])?,
+        distance: i64::from_str_radix(&hex[..5], 16).ok()?,
+    });
+}
+
+#[test]
+fn test_decode_hex() -> () {
+    assert_eq!(
+        Some(Instruction {
+            direction: (0, 1),
+            distance: 461937,
+        }),
+        decode_hex("R 6 (#70c710)")
+    );
+    assert_eq!(
+        Some(Instruction {
+            direction: (-1, 0),
+            distance: 500254,
+        }),
+        decode_hex("U 2 (#7a21e3)")
+    );
+    assert_eq!(None, decode_hex("R 6 (#70c71)"));
+}
+
+aoc::example_tests!(|input: &str| lagoon_volume(&parse_plan(input.lines(), decode_hex).unwrap()));
@@ -0,0 +1,57 @@
+mod workflows;
+
+use aoc::timing::timed;
+use std::io;
+use workflows::{parse_input, Condition, Part, System};
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let input = io::read_to_string(io::stdin())?;
+    let system = timed("parse", || parse_input(input.lines()))?;
+    print!("{}", timed("solve", || accepted_ratings(&system)));
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+fn matches(condition: &Condition, part: &Part) -> bool {
+    let rating = part[condition.category];
+    return if condition.less_than {
+        rating < condition.value
+    } else {
+        rating > condition.value
+    };
+}
+
+fn accepts(system: &System, part: &Part) -> bool {
+    let mut name = "in";
+    loop {
+        match name {
+            "A" => return true,
+            "R" => return false,
+            _ => {}
+        }
+        name = &system.workflows[name]
+            .iter()
+            .find(|rule| {
+                rule.condition
+                    .as_ref()
+                    .is_none_or(|condition| matches(condition, part))
+            })
+            .unwrap()
+            .target;
+    }
+}
+
+fn accepted_ratings(system: &System) -> u64 {
+    return system
+        .parts
+        .iter()
+        .filter(|part| accepts(system, part))
+        .map(|part| part.iter().sum::<u64>())
+        .sum();
+}
+
+aoc::example_tests!(|input: &str| accepted_ratings(&parse_input(input.lines()).unwrap()));
@@ -0,0 +1,27 @@
+mod ranges;
+mod workflows;
+
+use aoc::timing::timed;
+use ranges::{accepted_combinations, full_range};
+use std::io;
+use workflows::parse_input;
+
+aoc::count_allocations!();
+
+fn main() -> io::Result<()> {
+    let input = io::read_to_string(io::stdin())?;
+    let system = timed("parse", || parse_input(input.lines()))?;
+    print!(
+        "{}",
+        timed("solve", || accepted_combinations(&system, full_range()))
+    );
+
+    aoc::alloc::report();
+
+    return Ok(());
+}
+
+aoc::example_tests!(|input: &str| accepted_combinations(
+    &parse_input(input.lines()).unwrap(),
+    full_range()
+));
@@ -0,0 +1,91 @@
+use crate::workflows::{Condition, System};
+use aoc::interval::Interval;
+
+#[cfg(test)]
+use crate::workflows::parse_input;
+
+pub type PartRange = [Interval; 4];
+
+pub fn full_range() -> PartRange {
+    return [Interval::inclusive(1, 4000); 4];
+}
+
+/// Splits a range into the part that matches the condition and the part
+/// that falls through to the next rule.
+fn split(condition: &Condition, range: &PartRange) -> (Option<PartRange>, Option<PartRange>) {
+    let interval = range[condition.category];
+    let (matched, rest) = if condition.less_than {
+        interval.split_at(condition.value)
+    } else {
+        let (below, above) = interval.split_at(condition.value + 1);
+        (above, below)
+    };
+    let with = |interval: Interval| {
+        let mut range = *range;
+        range[condition.category] = interval;
+        range
+    };
+    return (matched.map(with), rest.map(with));
+}
+
+/// Counts accepted rating combinations within `range` by pushing
+/// hyper-rectangles through the workflows, splitting them at each rule.
+pub fn accepted_combinations(system: &System, range: PartRange) -> u64 {
+    let mut total = 0;
+    let mut pending = vec![("in", range)];
+    while let Some((name, range)) = pending.pop() {
+        match name {
+            "A" => {
+                total += range.iter().map(|interval| interval.len).product::<u64>();
+                continue;
+            }
+            "R" => continue,
+            _ => {}
+        }
+        let mut rest = Some(range);
+        for rule in &system.workflows[name] {
+            let Some(range) = rest else {
+                break;
+            };
+            match &rule.condition {
+                None => {
+                    pending.push((&rule.target, range));
+                    rest = None;
+                }
+                Some(condition) => {
+                    let (matched, unmatched) = split(condition, &range);
+                    if let Some(matched) = matched {
+                        pending.push((&rule.target, matched));
+                    }
+                    rest = unmatched;
+                }
+            }
+        }
+    }
+    return total;
+}
+
+#[test]
+fn test_split() -> () {
+    let range = full_range();
+    let condition = Condition {
+        category: 3,
+        less_than: false,
+        value: 2770,
+    };
+    let (matched, rest) = split(&condition, &range);
+    assert_eq!(Interval::inclusive(2771, 4000), matched.unwrap()[3]);
+    assert_eq!(Interval::inclusive(1, 2770), rest.unwrap()[3]);
+    assert_eq!(range[0], matched.unwrap()[0]);
+}
+
+#[test]
+fn test_accepted_combinations() -> () {
+    let system = parse_input("in{x<3:A,m>1:R,s>2:A,R}".lines()).unwrap();
+    // x < 3 accepts 2 * 4 * 4 * 4 parts; of the remaining 2 * 4 * 4 * 4, only
+    // the 2 * 1 * 4 * 2 with m = 1 and s > 2 are accepted.
+    assert_eq!(
+        128 + 16,
+        accepted_combinations(&system, [Interval::inclusive(1, 4); 4])
+    );
+}
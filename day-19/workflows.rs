@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::io;
+use std::str::Lines;
+
+pub type Part = [u64; 4];
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Condition {
+    pub category: usize,
+    pub less_than: bool,
+    pub value: u64,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Rule {
+    pub condition: Option<Condition>,
+    pub target: String,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct System {
+    pub workflows: HashMap<String, Vec<Rule>>,
+    pub parts: Vec<Part>,
+}
+
+fn category(name: &str) -> Option<usize> {
+    return "xmas".find(name).filter(|_| name.len() == 1);
+}
+
+fn parse_rule(rule: &str) -> Option<Rule> {
+    let Some((condition, target)) = rule.split_once(':') else {
+        return Some(Rule {
+            condition: None,
+            target: rule.to_string(),
+        });
+    };
+    let less_than = condition.contains('<');
+    let (name, value) = condition.split_once(if less_than { '<' } else { '>' })?;
+    return Some(Rule {
+        condition: Some(Condition {
+            category: category(name)?,
+            less_than,
+            value: value.parse().ok()?,
+        }),
+        target: target.to_string(),
+    });
+}
+
+fn parse_workflow(line: &str) -> Option<(String, Vec<Rule>)> {
+    let (name, rules) = line.strip_suffix('}')?.split_once('{')?;
+    return Some((
+        name.to_string(),
+        rules
+            .split(',')
+            .map(parse_rule)
+            .collect::<Option<Vec<Rule>>>()?,
+    ));
+}
+
+fn parse_part(line: &str) -> Option<Part> {
+    let mut part = [0; 4];
+    for rating in line.strip_prefix('{')?.strip_suffix('}')?.split(',') {
+        let (name, value) = rating.split_once('=')?;
+        part[category(name)?] = value.parse().ok()?;
+    }
+    return Some(part);
+}
+
+pub fn parse_input(lines: Lines) -> io::Result<System> {
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+    let mut workflows = HashMap::new();
+    let mut parts = vec![];
+    for (line_index, line) in lines
+        .map(|line| line.trim())
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+    {
+        if line.starts_with('{') {
+            parts.push(parse_part(line).ok_or_else(|| {
+                invalid(format!("line {}: invalid part: {:?}", line_index + 1, line))
+            })?);
+        } else {
+            let (name, rules) = parse_workflow(line)
+                .filter(|(_, rules)| rules.last().is_some_and(|rule| rule.condition.is_none()))
+                .ok_or_else(|| {
+                    invalid(format!(
+                        "line {}: invalid workflow: {:?}",
+                        line_index + 1,
+                        line
+                    ))
+                })?;
+            workflows.insert(name, rules);
+        }
+    }
+
+    if !workflows.contains_key("in") {
+        return Err(invalid("no \"in\" workflow".to_string()));
+    }
+    for (name, rules) in &workflows {
+        for rule in rules {
+            if !["A", "R"].contains(&rule.target.as_str()) && !workflows.contains_key(&rule.target)
+            {
+                return Err(invalid(format!(
+                    "workflow {} sends parts to unknown workflow {}",
+                    name, rule.target
+                )));
+            }
+        }
+    }
+    return Ok(System { workflows, parts });
+}
+
+#[test]
+fn test_parse_workflow() -> () {
+    assert_eq!(
+        Some((
+            "px".to_string(),
+            vec![
+                Rule {
+                    condition: Some(Condition {
+                        category: 2,
+                        less_than: true,
+                        value: 2006,
+                    }),
+                    target: "qkq".to_string(),
+                },
+                Rule {
+                    condition: Some(Condition {
+                        category: 1,
+                        less_than: false,
+                        value: 2090,
+                    }),
+                    target: "A".to_string(),
+                },
+                Rule {
+                    condition: None,
+                    target: "rfg".to_string(),
+                },
+            ]
+        )),
+        parse_workflow("px{a<2006:qkq,m>2090:A,rfg}")
+    );
+    assert_eq!(None, parse_workflow("px{q<2006:qkq,A}"));
+    assert_eq!(
+        Some([787, 2655, 1222, 2876]),
+        parse_part("{x=787,m=2655,a=1222,s=2876}")
+    );
+}
+
+#[test]
+fn test_parse_input() -> () {
+    let error = |input: &str| parse_input(input.lines()).unwrap_err().to_string();
+    assert_eq!(
+        "line 2: invalid part: \"{x=1,q=2}\"",
+        error("in{A}\n{x=1,q=2}")
+    );
+    assert_eq!(
+        "line 1: invalid workflow: \"in{x<3:A}\"",
+        error("in{x<3:A}")
+    );
+    assert_eq!("no \"in\" workflow", error("px{A}"));
+    assert_eq!(
+        "workflow in sends parts to unknown workflow px",
+        error("in{x<3:px,A}")
+    );
+}
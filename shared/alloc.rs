@@ -0,0 +1,101 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static CURRENT_HEAP: AtomicUsize = AtomicUsize::new(0);
+static PEAK_HEAP: AtomicUsize = AtomicUsize::new(0);
+
+#[macro_export]
+macro_rules! count_allocations {
+    () => {
+        #[cfg(feature = "alloc-stats")]
+        #[global_allocator]
+        static ALLOCATOR: $crate::alloc::CountingAllocator = $crate::alloc::CountingAllocator;
+    };
+}
+
+pub struct CountingAllocator;
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Stats {
+    pub allocations: usize,
+    pub bytes_allocated: usize,
+    pub peak_heap: usize,
+}
+
+fn record_allocation(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES_ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT_HEAP.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_HEAP.fetch_max(current, Ordering::Relaxed);
+}
+
+fn record_deallocation(size: usize) {
+    CURRENT_HEAP.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        return ptr;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        return ptr;
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_deallocation(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_deallocation(layout.size());
+            record_allocation(new_size);
+        }
+        return new_ptr;
+    }
+}
+
+pub fn stats() -> Stats {
+    return Stats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes_allocated: BYTES_ALLOCATED.load(Ordering::Relaxed),
+        peak_heap: PEAK_HEAP.load(Ordering::Relaxed),
+    };
+}
+
+pub fn report() {
+    if cfg!(feature = "alloc-stats") {
+        let stats = stats();
+        eprintln!(
+            "allocations: {}, bytes allocated: {}, peak heap: {}",
+            stats.allocations, stats.bytes_allocated, stats.peak_heap
+        );
+    }
+}
+
+#[test]
+fn test_counting_allocator() -> () {
+    let before = stats();
+    let layout = Layout::from_size_align(64, 8).unwrap();
+    unsafe {
+        let ptr = CountingAllocator.alloc(layout);
+        let ptr = CountingAllocator.realloc(ptr, layout, 128);
+        CountingAllocator.dealloc(ptr, Layout::from_size_align(128, 8).unwrap());
+    }
+    let after = stats();
+    assert!(after.allocations >= before.allocations + 2);
+    assert!(after.bytes_allocated >= before.bytes_allocated + 192);
+    assert!(after.peak_heap >= 128);
+}
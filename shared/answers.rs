@@ -0,0 +1,59 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub type Answers = BTreeMap<String, BTreeMap<String, String>>;
+
+pub fn load(path: &Path) -> io::Result<Answers> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Answers::new()),
+        Err(err) => return Err(err),
+    };
+    return toml::from_str(&contents)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()));
+}
+
+pub fn save(path: &Path, answers: &Answers) -> io::Result<()> {
+    let contents = toml::to_string(answers)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+    return fs::write(path, contents);
+}
+
+pub fn day_name(day: &str) -> Option<String> {
+    let number = day.trim_start_matches("day-").parse::<u32>().ok()?;
+    return Some(format!("day-{:02}", number));
+}
+
+pub fn part_name(part: &str) -> Option<String> {
+    let number = part.trim_start_matches("part-").parse::<u32>().ok()?;
+    return Some(format!("part-{:02}", number));
+}
+
+#[test]
+fn test_round_trip() -> () {
+    let mut answers = Answers::new();
+    answers
+        .entry("day-05".to_string())
+        .or_default()
+        .insert("part-01".to_string(), "35".to_string());
+    answers
+        .entry("day-01".to_string())
+        .or_default()
+        .insert("part-02".to_string(), "281".to_string());
+    let contents = toml::to_string(&answers).unwrap();
+    assert_eq!(
+        "[day-01]\npart-02 = \"281\"\n\n[day-05]\npart-01 = \"35\"\n",
+        contents
+    );
+    assert_eq!(answers, toml::from_str::<Answers>(&contents).unwrap());
+}
+
+#[test]
+fn test_names() -> () {
+    assert_eq!(Some("day-05".to_string()), day_name("5"));
+    assert_eq!(Some("day-12".to_string()), day_name("day-12"));
+    assert_eq!(Some("part-02".to_string()), part_name("2"));
+    assert_eq!(None, part_name("two"));
+}
@@ -0,0 +1,81 @@
+use std::fmt::Display;
+use std::io;
+use std::str::FromStr;
+
+/// The value following the first `flag`, or `None` when the flag is absent.
+pub fn flag_value<'a, S: AsRef<str>>(args: &'a [S], flag: &str) -> io::Result<Option<&'a str>> {
+    let Some(index) = args.iter().position(|arg| arg.as_ref() == flag) else {
+        return Ok(None);
+    };
+    return match args.get(index + 1) {
+        Some(value) => Ok(Some(value.as_ref())),
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} expects a value", flag),
+        )),
+    };
+}
+
+/// The whole number following `flag`, which must be at least `min`, or `None`
+/// when the flag is absent.
+pub fn number_flag<T, S>(args: &[S], flag: &str, min: T) -> io::Result<Option<T>>
+where
+    T: FromStr + PartialOrd + Display,
+    S: AsRef<str>,
+{
+    let invalid = |value: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "{} expects a whole number of at least {}, got {:?}",
+                flag, min, value
+            ),
+        )
+    };
+    let Some(value) = flag_value(args, flag).map_err(|_| invalid(""))? else {
+        return Ok(None);
+    };
+    return match value.parse::<T>() {
+        Ok(number) if number >= min => Ok(Some(number)),
+        _ => Err(invalid(value)),
+    };
+}
+
+#[test]
+fn test_flag_value() -> () {
+    let args = ["--bag", "red=1", "--html"];
+    assert_eq!(Some("red=1"), flag_value(&args, "--bag").unwrap());
+    assert_eq!(None, flag_value(&args, "--render").unwrap());
+    assert_eq!(
+        io::ErrorKind::InvalidInput,
+        flag_value(&args, "--html").unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_number_flag() -> () {
+    let none: [&str; 0] = [];
+    assert_eq!(None, number_flag::<u64, _>(&none, "--factor", 1).unwrap());
+    assert_eq!(
+        Some(10),
+        number_flag::<u64, _>(&["--factor", "10"], "--factor", 1).unwrap()
+    );
+    assert_eq!(
+        Some(0),
+        number_flag::<usize, _>(&["--arity", "0"], "--arity", 0).unwrap()
+    );
+    for invalid in [&["--factor"][..], &["--factor", "0"], &["--factor", "ten"]] {
+        assert_eq!(
+            io::ErrorKind::InvalidInput,
+            number_flag::<u64, _>(invalid, "--factor", 1)
+                .unwrap_err()
+                .kind()
+        );
+    }
+    assert_eq!(
+        "--factor expects a whole number of at least 1, got \"ten\"",
+        number_flag::<u64, _>(&["--factor", "ten"], "--factor", 1)
+            .unwrap_err()
+            .to_string()
+    );
+}
@@ -0,0 +1,90 @@
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+
+#[macro_export]
+macro_rules! example_tests {
+    ($solve:expr) => {
+        #[test]
+        fn test_examples() -> () {
+            $crate::examples::check(env!("CARGO_MANIFEST_DIR"), file!(), $solve);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! example {
+    ($name:expr) => {
+        $crate::examples::read(env!("CARGO_MANIFEST_DIR"), file!(), $name)
+    };
+}
+
+pub fn read(manifest_dir: &str, source_file: &str, name: &str) -> String {
+    let source = Path::new(manifest_dir).join(source_file);
+    let path = source
+        .parent()
+        .unwrap()
+        .join("examples")
+        .join(format!("{}.txt", name));
+    return fs::read_to_string(&path).unwrap_or_else(|err| panic!("{:?}: {}", path, err));
+}
+
+pub fn check<T: Display>(manifest_dir: &str, source_file: &str, solve: impl Fn(&str) -> T) {
+    let source = Path::new(manifest_dir).join(source_file);
+    let part = source.file_stem().unwrap().to_string_lossy().to_string();
+    let examples_dir = source.parent().unwrap().join("examples");
+
+    let mut checked = 0;
+    let mut failures = vec![];
+    for (name, input, expected) in load(&examples_dir, &part) {
+        let actual = solve(&input).to_string();
+        if actual != expected {
+            failures.push(format!("{}: expected {}, got {}", name, expected, actual));
+        }
+        checked += 1;
+    }
+
+    assert!(
+        checked > 0,
+        "no examples for {} in {:?}",
+        part,
+        examples_dir
+    );
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+pub fn load(examples_dir: &Path, part: &str) -> Vec<(String, String, String)> {
+    let mut examples = vec![];
+    let Ok(entries) = fs::read_dir(examples_dir) else {
+        return examples;
+    };
+    let mut paths: Vec<_> = entries.map(|entry| entry.unwrap().path()).collect();
+    paths.sort();
+    for path in paths {
+        if path.extension().is_none_or(|ext| ext != "expected") {
+            continue;
+        }
+        let answers = fs::read_to_string(&path).unwrap();
+        if let Some(expected) = parse_answer(&answers, part) {
+            let name = path.file_stem().unwrap().to_string_lossy().to_string();
+            let input = fs::read_to_string(path.with_extension("txt")).unwrap();
+            examples.push((name, input, expected));
+        }
+    }
+    return examples;
+}
+
+pub fn parse_answer(answers: &str, part: &str) -> Option<String> {
+    return answers.lines().find_map(|line| {
+        let (key, value) = line.split_once("=")?;
+        (key.trim() == part).then(|| value.trim().to_string())
+    });
+}
+
+#[test]
+fn test_parse_answer() -> () {
+    let answers = "part-01 = 35\npart-02 = 46\n";
+    assert_eq!(Some("35".to_string()), parse_answer(answers, "part-01"));
+    assert_eq!(Some("46".to_string()), parse_answer(answers, "part-02"));
+    assert_eq!(None, parse_answer("part-01 = 2", "part-02"));
+}
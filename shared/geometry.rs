@@ -0,0 +1,41 @@
+use num::integer::gcd;
+
+pub type Point = (i64, i64);
+
+/// Twice the area of a simple polygon, by the shoelace formula.
+pub fn doubled_area(vertices: &[Point]) -> i64 {
+    return vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1)
+        .sum::<i64>()
+        .abs();
+}
+
+/// Number of lattice points on the polygon's edges.
+pub fn boundary_points(vertices: &[Point]) -> i64 {
+    return vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|((x1, y1), (x2, y2))| gcd(x2 - x1, y2 - y1))
+        .sum();
+}
+
+/// Number of lattice points strictly inside the polygon, by Pick's theorem:
+/// `A = i + b / 2 - 1`.
+pub fn interior_points(vertices: &[Point]) -> i64 {
+    return (doubled_area(vertices) - boundary_points(vertices)) / 2 + 1;
+}
+
+#[test]
+fn test_polygon() -> () {
+    let square = [(0, 0), (4, 0), (4, 4), (0, 4)];
+    assert_eq!(32, doubled_area(&square));
+    assert_eq!(16, boundary_points(&square));
+    assert_eq!(9, interior_points(&square));
+
+    let triangle = [(0, 0), (4, 0), (0, 2)];
+    assert_eq!(8, doubled_area(&triangle));
+    assert_eq!(8, boundary_points(&triangle));
+    assert_eq!(1, interior_points(&triangle));
+}
@@ -0,0 +1,321 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+pub type Position = (usize, usize);
+
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn offset(self) -> (isize, isize) {
+        return match self {
+            Direction::North => (-1, 0),
+            Direction::East => (0, 1),
+            Direction::South => (1, 0),
+            Direction::West => (0, -1),
+        };
+    }
+
+    pub fn turn_right(self) -> Direction {
+        return Direction::ALL[(self as usize + 1) % 4];
+    }
+
+    pub fn turn_left(self) -> Direction {
+        return Direction::ALL[(self as usize + 3) % 4];
+    }
+
+    pub fn reverse(self) -> Direction {
+        return Direction::ALL[(self as usize + 2) % 4];
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    pub fn parse(input: &str) -> Grid<char> {
+        return Grid::from_lines(input.lines(), |c| c);
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(width * height, cells.len(), "grid cells do not match size");
+        return Grid {
+            width,
+            height,
+            cells,
+        };
+    }
+
+    pub fn from_lines<'a>(
+        lines: impl Iterator<Item = &'a str>,
+        cell: impl Fn(char) -> T,
+    ) -> Grid<T> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in lines
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+        {
+            let row_len = line.chars().count();
+            assert!(
+                height == 0 || row_len == width,
+                "grid rows differ in length"
+            );
+            width = row_len;
+            height += 1;
+            cells.extend(line.chars().map(&cell));
+        }
+        return Grid::new(width, height, cells);
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn get(&self, (row, col): Position) -> Option<&T> {
+        if row >= self.height || col >= self.width {
+            return None;
+        }
+        return self.cells.get(row * self.width + col);
+    }
+
+    pub fn get_mut(&mut self, (row, col): Position) -> Option<&mut T> {
+        if row >= self.height || col >= self.width {
+            return None;
+        }
+        return self.cells.get_mut(row * self.width + col);
+    }
+
+    pub fn set(&mut self, position: Position, value: T) {
+        if let Some(cell) = self.get_mut(position) {
+            *cell = value;
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "row {} outside grid", row);
+        return &self.cells[row * self.width..(row + 1) * self.width];
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        return (0..self.height).map(move |row| self.row(row));
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.width, "column {} outside grid", col);
+        return (0..self.height).map(move |row| &self.cells[row * self.width + col]);
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        return (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        return self.offsets(position, &[(-1, 0), (0, -1), (0, 1), (1, 0)]);
+    }
+
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        return self.offsets(
+            position,
+            &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        );
+    }
+
+    pub fn step(&self, (row, col): Position, (d_row, d_col): (isize, isize)) -> Option<Position> {
+        let row = row.checked_add_signed(d_row)?;
+        let col = col.checked_add_signed(d_col)?;
+        if row >= self.height || col >= self.width {
+            return None;
+        }
+        return Some((row, col));
+    }
+
+    pub fn step_towards(&self, position: Position, direction: Direction) -> Option<Position> {
+        return self.step(position, direction.offset());
+    }
+
+    fn offsets(
+        &self,
+        position: Position,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + '_ {
+        return offsets
+            .iter()
+            .filter_map(move |offset| self.step(position, *offset));
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        return Grid::new(self.width, self.height, self.cells.iter().map(f).collect());
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        return Grid::new(width, height, vec![value; width * height]);
+    }
+
+    pub fn transpose(&self) -> Grid<T> {
+        return Grid::new(
+            self.height,
+            self.width,
+            (0..self.width)
+                .flat_map(|col| self.column(col).cloned())
+                .collect(),
+        );
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        return Grid::new(
+            self.height,
+            self.width,
+            (0..self.width)
+                .flat_map(|col| self.column(col).rev().cloned().collect::<Vec<T>>())
+                .collect(),
+        );
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T> {
+        return Grid::new(
+            self.height,
+            self.width,
+            (0..self.width)
+                .rev()
+                .flat_map(|col| self.column(col).cloned().collect::<Vec<T>>())
+                .collect(),
+        );
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        return self.get(position).expect("position outside grid");
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        return self.get_mut(position).expect("position outside grid");
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        return Ok(());
+    }
+}
+
+#[test]
+fn test_parse() -> () {
+    let grid = Grid::parse("ab\ncd\nef\n");
+    assert_eq!(2, grid.width());
+    assert_eq!(3, grid.height());
+    assert_eq!(Some(&'d'), grid.get((1, 1)));
+    assert_eq!(None, grid.get((1, 2)));
+    assert_eq!(None, grid.get((3, 0)));
+    assert_eq!('c', grid[(1, 0)]);
+    assert_eq!(&['e', 'f'], grid.row(2));
+    assert_eq!(
+        vec!['b', 'd', 'f'],
+        grid.column(1).copied().collect::<Vec<char>>()
+    );
+    assert_eq!("ab\ncd\nef\n", grid.to_string());
+}
+
+#[test]
+#[should_panic(expected = "column 2 outside grid")]
+fn test_column_out_of_bounds() -> () {
+    let _ = Grid::parse("ab\ncd").column(2);
+}
+
+#[test]
+#[should_panic(expected = "row 2 outside grid")]
+fn test_row_out_of_bounds() -> () {
+    Grid::parse("ab\ncd").row(2);
+}
+
+#[test]
+fn test_neighbours() -> () {
+    let grid = Grid::parse("abc\ndef\nghi");
+    assert_eq!(
+        vec![(0, 1), (1, 0)],
+        grid.neighbours4((0, 0)).collect::<Vec<Position>>()
+    );
+    assert_eq!(
+        vec![(0, 1), (1, 0), (1, 1)],
+        grid.neighbours8((0, 0)).collect::<Vec<Position>>()
+    );
+    assert_eq!(8, grid.neighbours8((1, 1)).count());
+    assert_eq!(
+        vec![(1, 1), (1, 2), (2, 1)],
+        grid.neighbours8((2, 2)).collect::<Vec<Position>>()
+    );
+}
+
+#[test]
+fn test_direction() -> () {
+    let grid = Grid::parse("abc\ndef");
+    assert_eq!(Direction::East, Direction::North.turn_right());
+    assert_eq!(Direction::North, Direction::North.turn_left().turn_right());
+    assert_eq!(Direction::West, Direction::North.turn_left());
+    assert_eq!(Direction::South, Direction::North.reverse());
+    assert_eq!(Some((0, 2)), grid.step_towards((1, 2), Direction::North));
+    assert_eq!(None, grid.step_towards((1, 2), Direction::East));
+    assert_eq!(None, grid.step_towards((1, 0), Direction::South));
+}
+
+#[test]
+fn test_transform() -> () {
+    let grid = Grid::parse("abc\ndef");
+    assert_eq!(Grid::parse("ad\nbe\ncf"), grid.transpose());
+    assert_eq!(Grid::parse("da\neb\nfc"), grid.rotate_clockwise());
+    assert_eq!(Grid::parse("cf\nbe\nad"), grid.rotate_counterclockwise());
+    assert_eq!(
+        grid,
+        grid.rotate_clockwise()
+            .rotate_clockwise()
+            .rotate_clockwise()
+            .rotate_clockwise()
+    );
+}
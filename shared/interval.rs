@@ -0,0 +1,53 @@
+/// A half-open range of integers `start..start + len`, stored the same way as
+/// the almanac's `(start, len)` seed ranges.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct Interval {
+    pub start: u64,
+    pub len: u64,
+}
+
+impl Interval {
+    pub fn new(start: u64, len: u64) -> Interval {
+        return Interval { start, len };
+    }
+
+    pub fn inclusive(first: u64, last: u64) -> Interval {
+        return Interval::new(first, (last + 1).saturating_sub(first));
+    }
+
+    pub fn end(&self) -> u64 {
+        return self.start + self.len;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    /// Splits into the values below `value` and the values from `value` on,
+    /// dropping either side when it is empty.
+    pub fn split_at(&self, value: u64) -> (Option<Interval>, Option<Interval>) {
+        let middle = value.clamp(self.start, self.end());
+        let below = Interval::new(self.start, middle - self.start);
+        let above = Interval::new(middle, self.end() - middle);
+        return (
+            Some(below).filter(|interval| !interval.is_empty()),
+            Some(above).filter(|interval| !interval.is_empty()),
+        );
+    }
+}
+
+#[test]
+fn test_split_at() -> () {
+    let interval = Interval::inclusive(1, 4000);
+    assert_eq!(4000, interval.len);
+    assert_eq!(
+        (
+            Some(Interval::inclusive(1, 1350)),
+            Some(Interval::inclusive(1351, 4000))
+        ),
+        interval.split_at(1351)
+    );
+    assert_eq!((None, Some(interval)), interval.split_at(0));
+    assert_eq!((Some(interval), None), interval.split_at(4001));
+    assert!(Interval::inclusive(5, 4).is_empty());
+}
@@ -0,0 +1,8 @@
+pub mod alloc;
+pub mod answers;
+pub mod args;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod interval;
+pub mod timing;
@@ -0,0 +1,32 @@
+use std::env;
+use std::time::Instant;
+
+pub const TIMINGS_VAR: &str = "AOC_TIMINGS";
+pub const TIMING_PREFIX: &str = "aoc-timing";
+
+pub fn timed<T>(phase: &str, f: impl FnOnce() -> T) -> T {
+    if env::var_os(TIMINGS_VAR).is_none() {
+        return f();
+    }
+    let start = Instant::now();
+    let result = f();
+    eprintln!("{} {} {}", TIMING_PREFIX, phase, start.elapsed().as_nanos());
+    return result;
+}
+
+pub fn parse_timing(line: &str) -> Option<(&str, u128)> {
+    let mut parts = line.split_whitespace();
+    if parts.next()? != TIMING_PREFIX {
+        return None;
+    }
+    let phase = parts.next()?;
+    let nanos = parts.next()?.parse::<u128>().ok()?;
+    return Some((phase, nanos));
+}
+
+#[test]
+fn test_parse_timing() -> () {
+    assert_eq!(Some(("parse", 1200)), parse_timing("aoc-timing parse 1200"));
+    assert_eq!(None, parse_timing("warning: card 3 wins copies"));
+    assert_eq!(None, parse_timing("aoc-timing solve"));
+}
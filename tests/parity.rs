@@ -0,0 +1,154 @@
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+fn manifest_dir() -> &'static Path {
+    return Path::new(env!("CARGO_MANIFEST_DIR"));
+}
+
+fn deno_available() -> bool {
+    return Command::new("deno")
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success());
+}
+
+fn bin_path(name: &str) -> PathBuf {
+    let deps_dir = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    return deps_dir
+        .parent()
+        .unwrap()
+        .join(format!("{}{}", name, env::consts::EXE_SUFFIX));
+}
+
+fn shared_solutions() -> Vec<(String, String)> {
+    let mut solutions = vec![];
+    for entry in fs::read_dir(manifest_dir()).unwrap() {
+        let day_dir = entry.unwrap().path();
+        let day = day_dir.file_name().unwrap().to_string_lossy().to_string();
+        if !day.starts_with("day-") || !day_dir.is_dir() {
+            continue;
+        }
+        for file in fs::read_dir(&day_dir).unwrap() {
+            let path = file.unwrap().path();
+            if path.extension().is_some_and(|ext| ext == "ts") && path.with_extension("rs").exists()
+            {
+                let part = path.file_stem().unwrap().to_string_lossy().to_string();
+                solutions.push((day.clone(), part));
+            }
+        }
+    }
+    solutions.sort();
+    return solutions;
+}
+
+fn run(command: &mut Command, input: &str) -> String {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(
+        output.status.success(),
+        "{:?} failed: {}",
+        command,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    return String::from_utf8_lossy(&output.stdout).trim().to_string();
+}
+
+fn inputs(day: &str) -> Vec<String> {
+    let mut inputs = vec![];
+    if let Ok(entries) = fs::read_dir(manifest_dir().join(day).join("examples")) {
+        let mut paths: Vec<PathBuf> = entries.map(|entry| entry.unwrap().path()).collect();
+        paths.sort();
+        for path in paths {
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                inputs.push(fs::read_to_string(path).unwrap());
+            }
+        }
+    }
+    if day == "day-04" {
+        for seed in 1..=5 {
+            inputs.push(generate_scratchcards(seed, 40));
+        }
+    }
+    return inputs;
+}
+
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, bound: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        return (self.0 >> 33) % bound;
+    }
+}
+
+fn generate_scratchcards(seed: u64, cards: usize) -> String {
+    let mut rng = Lcg(seed);
+    let mut input = String::new();
+    for id in 1..=cards {
+        let mut numbers: Vec<u64> = (1..100).collect();
+        for i in (1..numbers.len()).rev() {
+            numbers.swap(i, rng.next(i as u64 + 1) as usize);
+        }
+        let matches = (rng.next(6) as usize).min(cards - id);
+        let winning = &numbers[0..5];
+        let have: Vec<u64> = numbers[0..matches]
+            .iter()
+            .chain(&numbers[5..(13 - matches)])
+            .copied()
+            .collect();
+        let format = |ns: &[u64]| {
+            ns.iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+        input.push_str(&format!(
+            "Card {:>3}: {} | {}\n",
+            id,
+            format(winning),
+            format(&have)
+        ));
+    }
+    return input;
+}
+
+#[test]
+fn test_shared_solutions() -> () {
+    let solutions = shared_solutions();
+    assert!(solutions.contains(&("day-04".to_string(), "part-01".to_string())));
+    assert!(solutions.contains(&("day-04".to_string(), "part-02".to_string())));
+}
+
+#[test]
+fn test_deno_parity() -> () {
+    if !deno_available() {
+        eprintln!("deno not found, skipping cross-language parity checks");
+        return;
+    }
+    for (day, part) in shared_solutions() {
+        let script = manifest_dir().join(&day).join(format!("{}.ts", part));
+        let bin = bin_path(&format!("{}-{}", day, part));
+        for input in inputs(&day) {
+            let deno = run(Command::new("deno").arg("run").arg(&script), &input);
+            let rust = run(&mut Command::new(&bin), &input);
+            assert_eq!(deno, rust, "{} {} disagree on input:\n{}", day, part, input);
+        }
+    }
+}